@@ -0,0 +1,35 @@
+//! This build script solves Two-Four-Eighteen for every objective and writes the value
+//! tables into the output directory, so the device never has to run the search itself.
+
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+#[allow(dead_code)]
+#[path = "src/two_four_eighteen/solver.rs"]
+mod solver;
+
+use solver::{Objective, ValueTable};
+
+fn main() {
+    let out = &PathBuf::from(env::var_os("OUT_DIR").unwrap());
+
+    for (objective, file_name) in [
+        (Objective::ExpectedScore, "expected_score_table.rs"),
+        (Objective::WinProbability, "win_probability_table.rs"),
+    ] {
+        let table = ValueTable::solve(objective);
+        let values: Vec<String> = table
+            .values()
+            .iter()
+            .map(|value| format!("{value:?}"))
+            .collect();
+        File::create(out.join(file_name))
+            .unwrap()
+            .write_all(format!("ValueTable::from_values([{}])", values.join(", ")).as_bytes())
+            .unwrap();
+    }
+
+    println!("cargo:rerun-if-changed=src/two_four_eighteen/solver.rs");
+}
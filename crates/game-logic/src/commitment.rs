@@ -0,0 +1,119 @@
+pub mod sha256;
+
+use core::fmt;
+
+use crate::dice_game::DiceGame;
+use sha256::{Digest, sha256};
+
+/// Hashed in front of the seed, so that the hash commits to a seed of the hub and nothing
+/// else.
+const DOMAIN: &[u8] = b"pico-dice seed";
+/// The start of the hash shown before the first roll, short enough to fit on a line of the
+/// display and long enough that no other seed can be found to match it.
+pub const SHORT_HASH_BYTES: usize = 8;
+/// A game that is not over after this many rolls was not played by this hub.
+pub const MAX_ROLLS: usize = 10_000;
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum VerifyError {
+    /// The seed does not hash to what was shown before the first roll.
+    HashMismatch,
+    /// Replaying the game from the seed never ended.
+    NotOver,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyError::HashMismatch => f.write_str("the seed does not match the hash"),
+            VerifyError::NotOver => write!(f, "the game is not over after {MAX_ROLLS} rolls"),
+        }
+    }
+}
+
+/// The hash of a seed, shown before the first roll of a game and checked against the seed
+/// revealed once the game is over. Neither can the seed be guessed from the hash, nor can the
+/// hub switch to another seed halfway through the game.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Commitment {
+    hash: Digest,
+}
+
+impl Commitment {
+    pub fn to_seed(seed: u64) -> Self {
+        let mut message = [0u8; DOMAIN.len() + 8];
+        message[..DOMAIN.len()].copy_from_slice(DOMAIN);
+        message[DOMAIN.len()..].copy_from_slice(&seed.to_le_bytes());
+        Self {
+            hash: sha256(&message),
+        }
+    }
+
+    pub fn hash(&self) -> &Digest {
+        &self.hash
+    }
+
+    /// The start of the hash as shown on the display.
+    pub fn short(&self) -> ShortHash {
+        let mut short = [0u8; SHORT_HASH_BYTES];
+        short.copy_from_slice(&self.hash[..SHORT_HASH_BYTES]);
+        ShortHash(short)
+    }
+
+    /// Whether the hex digits, the whole hash or at least the short one, are the start of the
+    /// hash. Case and whitespace do not matter.
+    pub fn matches(&self, shown: &str) -> bool {
+        let mut digits = shown.chars().filter(|c| !c.is_whitespace());
+        let mut matched = 0;
+        for byte in self.hash {
+            for nibble in [byte >> 4, byte & 0x0f] {
+                match digits.next() {
+                    Some(digit) if digit.to_digit(16) == Some(nibble as u32) => matched += 1,
+                    Some(_) => return false,
+                    None => return matched >= 2 * SHORT_HASH_BYTES,
+                }
+            }
+        }
+        digits.next().is_none()
+    }
+}
+
+/// The whole hash in hex.
+impl fmt::Display for Commitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, &self.hash)
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct ShortHash([u8; SHORT_HASH_BYTES]);
+
+impl fmt::Display for ShortHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_hex(f, &self.0)
+    }
+}
+
+/// Checks the revealed seed against the hash shown before the first roll, then replays the
+/// game from the seed. Returns the final score, which has to be the one the hub showed. The
+/// game has to be set up like the one on the hub, with the same rules and strategy.
+pub fn verify<G>(game: &mut G, seed: u64, shown: &str) -> Result<i16, VerifyError>
+where
+    G: DiceGame,
+{
+    if !Commitment::to_seed(seed).matches(shown) {
+        return Err(VerifyError::HashMismatch);
+    }
+    game.reseed(seed);
+    for _ in 0..MAX_ROLLS {
+        if let Some(score) = game.final_score() {
+            return Ok(score);
+        }
+        game.roll();
+    }
+    game.final_score().ok_or(VerifyError::NotOver)
+}
+
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8]) -> fmt::Result {
+    bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+}
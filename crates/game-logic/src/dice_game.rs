@@ -0,0 +1,82 @@
+use core::fmt;
+use pico_display::dice::Dice;
+use pico_display::die::Die;
+
+/// How a game ended, along with its final score.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Outcome {
+    Playing,
+    Won(i16),
+    Lost(i16),
+    Over(i16),
+}
+
+/// A dice game played one roll per break of the beam.
+pub trait DiceGame {
+    /// A single roll as it is logged while playing.
+    type Event: fmt::Display;
+    /// The record of a whole game, logged once it is over.
+    type Log: fmt::Display;
+
+    /// Shown when the game is lost.
+    const LOST_MESSAGE: &'static str;
+
+    /// Rolls the dice and plays the roll.
+    fn roll(&mut self);
+
+    /// The dice of the last roll.
+    fn rolled(&self) -> &Dice;
+
+    /// The dice that make up the score.
+    fn kept(&self) -> &Dice;
+
+    /// The dice kept in rolls before the last one, shown next to it.
+    fn kept_before_roll(&self) -> &[Die] {
+        &[]
+    }
+
+    /// Whether the die at `index` of the last roll was kept rather than going back into play.
+    fn is_kept(&self, _index: usize) -> bool {
+        false
+    }
+
+    /// The outcome of the game, `Outcome::Playing` as long as there is something left to roll.
+    fn outcome(&self) -> Outcome;
+
+    /// Starts the next game, whose dice roll on from the rng of the last one. Only the state of
+    /// the game is cleared, `reseed` is what changes the rng.
+    fn reset(&mut self);
+
+    /// Starts the next game with dice rolled from `seed`, e.g. one drawn from hardware
+    /// entropy instead of the game's own rng.
+    fn reseed(&mut self, seed: u64);
+
+    fn last_event(&self) -> Option<&Self::Event>;
+
+    fn log(&self) -> &Self::Log;
+
+    /// The final score once the game is over.
+    fn final_score(&self) -> Option<i16> {
+        match self.outcome() {
+            Outcome::Playing => None,
+            Outcome::Won(score) | Outcome::Lost(score) | Outcome::Over(score) => Some(score),
+        }
+    }
+
+    fn is_over(&self) -> bool {
+        self.outcome() != Outcome::Playing
+    }
+}
+
+/// Whether the die at `index` of `rolled` is one of `kept`. Of dice showing the same face the
+/// ones rolled first count as kept, like `Dice::pick` takes them.
+pub(crate) fn is_kept_from(rolled: &Dice, kept: &[Die], index: usize) -> bool {
+    let Some(die) = rolled.dice.get(index) else {
+        return false;
+    };
+    let rolled_before = rolled.dice[..index]
+        .iter()
+        .filter(|other| *other == die)
+        .count();
+    kept.iter().filter(|other| *other == die).count() > rolled_before
+}
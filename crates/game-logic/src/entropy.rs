@@ -0,0 +1,88 @@
+use rand::RngCore;
+use rand::rand_core::impls;
+
+/// Added to the counter for every output, the golden ratio as in SplitMix64.
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Where a sample came from. It is mixed in along with the sample, so that equal samples of
+/// different sources do not cancel each other out.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Source {
+    /// The random bit of the ring oscillator.
+    RingOscillator,
+    /// The lowest bits of reading a floating ADC pin.
+    AdcNoise,
+    /// The ticks of the timer since boot.
+    Timer,
+    /// How long the beam was broken, in microseconds.
+    BeamTiming,
+}
+
+impl Source {
+    fn tag(self) -> u64 {
+        match self {
+            Source::RingOscillator => 0x524f_5343,
+            Source::AdcNoise => 0x4144_4300,
+            Source::Timer => 0x5449_4d45,
+            Source::BeamTiming => 0x4245_414d,
+        }
+    }
+}
+
+/// Pools samples of several weak sources of randomness into seeds. None of the sources is
+/// enough on its own, but every sample changes every bit of the pool, so the seeds are as hard
+/// to guess as all of the samples together.
+///
+/// This is not a cryptographic generator, it only has to be good enough to roll dice with.
+#[derive(Clone, Debug, Default)]
+pub struct EntropyPool {
+    state: u64,
+    counter: u64,
+    samples: u32,
+}
+
+impl EntropyPool {
+    pub const fn new() -> Self {
+        Self {
+            state: 0,
+            counter: 0,
+            samples: 0,
+        }
+    }
+
+    pub fn mix(&mut self, source: Source, sample: u64) {
+        self.state = mix64(self.state ^ mix64(sample ^ source.tag()));
+        self.samples = self.samples.wrapping_add(1);
+    }
+
+    /// The number of samples mixed in so far.
+    pub fn samples(&self) -> u32 {
+        self.samples
+    }
+}
+
+impl RngCore for EntropyPool {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Draws from the pool and feeds the output back, so that no two draws are the same even
+    /// without new samples in between.
+    fn next_u64(&mut self) -> u64 {
+        self.counter = self.counter.wrapping_add(GOLDEN_GAMMA);
+        let output = mix64(self.state.wrapping_add(self.counter));
+        self.state = mix64(self.state ^ output.rotate_left(32));
+        output
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+/// The SplitMix64 finalizer, every input bit flips about half of the output bits.
+fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,301 @@
+pub mod scoring;
+pub mod strategy;
+
+use core::fmt;
+use pico_display::dice::Dice;
+use pico_display::die::Die;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::dice_game::{self, DiceGame, Outcome};
+use scoring::{is_farkle, score, scoring_dice};
+use strategy::{DefaultStrategy, Strategy};
+
+pub const NUMBER_OF_DICE: u8 = 6;
+pub const TARGET_SCORE: u16 = 10_000;
+pub const MAX_TURNS: usize = 20;
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Action {
+    /// Nothing scored, the turn score is lost.
+    Farkle,
+    Bank,
+    Risk,
+    /// Every die scored, all six dice are rolled again.
+    HotDice,
+}
+
+/// A single roll along with what was made of it.
+#[derive(Clone)]
+pub struct Event {
+    pub rolled: Dice,
+    pub kept: Dice,
+    pub turn_score: u16,
+    pub total: u16,
+    pub action: Action,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_faces = |f: &mut fmt::Formatter<'_>, dice: &Dice| {
+            dice.dice
+                .iter()
+                .try_for_each(|die| write!(f, "{}", die.value.as_u8()))
+        };
+        f.write_str("rolled: ")?;
+        write_faces(f, &self.rolled)?;
+        f.write_str(", kept: ")?;
+        write_faces(f, &self.kept)?;
+        write!(
+            f,
+            ", turn score: {}, total: {}, {}",
+            self.turn_score,
+            self.total,
+            match self.action {
+                Action::Farkle => "farkle",
+                Action::Bank => "bank",
+                Action::Risk => "risk",
+                Action::HotDice => "hot dice",
+            }
+        )
+    }
+}
+
+/// The banked score of every finished turn.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct TurnLog {
+    scores: [u16; MAX_TURNS],
+    len: u8,
+}
+
+impl TurnLog {
+    fn push(&mut self, score: u16) {
+        if let Some(slot) = self.scores.get_mut(self.len as usize) {
+            *slot = score;
+            self.len += 1;
+        }
+    }
+
+    pub fn scores(&self) -> &[u16] {
+        &self.scores[..self.len as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl fmt::Display for TurnLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("turns:")?;
+        self.scores()
+            .iter()
+            .try_for_each(|score| write!(f, " {score}"))
+    }
+}
+
+/// Solitaire Farkle: reach the target score within a limited number of turns.
+pub struct Game<S = DefaultStrategy> {
+    pub small_rng: SmallRng,
+    pub strategy: S,
+    pub rolled: Dice,
+    /// The dice kept during the current or the last turn.
+    pub kept: Dice,
+    pub dice_left: u8,
+    pub turn_score: u16,
+    pub total: u16,
+    turns: TurnLog,
+    in_turn: bool,
+    last_event: Option<Event>,
+}
+
+impl Game {
+    pub fn new(small_rng: SmallRng) -> Self {
+        Self::with_strategy(small_rng, DefaultStrategy)
+    }
+}
+
+impl<S> Game<S>
+where
+    S: Strategy,
+{
+    pub fn with_strategy(small_rng: SmallRng, strategy: S) -> Self {
+        Self {
+            small_rng,
+            strategy,
+            rolled: Dice::empty(),
+            kept: Dice::empty(),
+            dice_left: NUMBER_OF_DICE,
+            turn_score: 0,
+            total: 0,
+            turns: TurnLog::default(),
+            in_turn: false,
+            last_event: None,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.rolled = Dice::empty();
+        self.kept = Dice::empty();
+        self.dice_left = NUMBER_OF_DICE;
+        self.turn_score = 0;
+        self.total = 0;
+        self.turns = TurnLog::default();
+        self.in_turn = false;
+        self.last_event = None;
+    }
+
+    /// Rolls the dice left, keeps the scoring dice chosen by the strategy and either banks the
+    /// turn or stays in it.
+    pub fn roll(&mut self) {
+        if self.is_over() {
+            return;
+        }
+        if !self.in_turn {
+            self.kept = Dice::empty();
+            self.dice_left = NUMBER_OF_DICE;
+            self.turn_score = 0;
+            self.in_turn = true;
+        }
+
+        let face_value = || self.small_rng.random();
+        self.rolled = Dice::roll(face_value, self.dice_left as u32);
+        if is_farkle(&self.rolled) {
+            self.turn_score = 0;
+            self.end_turn();
+            self.record(Dice::empty(), Action::Farkle);
+            return;
+        }
+
+        let kept = self.keep();
+        self.turn_score = self
+            .turn_score
+            .saturating_add(score(&kept).unwrap_or_default());
+        self.dice_left -= kept.len() as u8;
+        self.kept.append(&mut kept.clone());
+
+        let hot_dice = self.dice_left == 0;
+        if hot_dice {
+            self.dice_left = NUMBER_OF_DICE;
+        }
+        let needed = TARGET_SCORE.saturating_sub(self.total);
+        let action = if self.strategy.bank(self.turn_score, self.dice_left, needed) {
+            self.total = self.total.saturating_add(self.turn_score);
+            self.end_turn();
+            Action::Bank
+        } else if hot_dice {
+            Action::HotDice
+        } else {
+            Action::Risk
+        };
+        self.record(kept, action);
+    }
+
+    pub fn turns(&self) -> &TurnLog {
+        &self.turns
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.has_won() || self.turns.len() >= MAX_TURNS
+    }
+
+    pub fn has_won(&self) -> bool {
+        self.total >= TARGET_SCORE
+    }
+
+    /// The dice to keep according to the strategy, or every scoring die if the strategy
+    /// returned dice that were not rolled or do not score.
+    fn keep(&mut self) -> Dice {
+        let kept = self.strategy.keep(&self.rolled, self.turn_score);
+
+        let mut rolled = self.rolled.clone();
+        let all_rolled = kept
+            .dice
+            .iter()
+            .all(|die| !rolled.pick(|value| value == die.value, Some(1)).is_empty());
+        if all_rolled && !kept.is_empty() && score(&kept).is_some() {
+            kept
+        } else {
+            scoring_dice(&self.rolled)
+        }
+    }
+
+    fn end_turn(&mut self) {
+        self.turns.push(self.turn_score);
+        self.in_turn = false;
+    }
+
+    fn record(&mut self, kept: Dice, action: Action) {
+        self.last_event = Some(Event {
+            rolled: self.rolled.clone(),
+            kept,
+            turn_score: self.turn_score,
+            total: self.total,
+            action,
+        });
+    }
+}
+
+impl<S> DiceGame for Game<S>
+where
+    S: Strategy,
+{
+    type Event = Event;
+    type Log = TurnLog;
+
+    const LOST_MESSAGE: &'static str = "Out of\nturns!";
+
+    fn roll(&mut self) {
+        Game::roll(self);
+    }
+
+    fn rolled(&self) -> &Dice {
+        &self.rolled
+    }
+
+    fn kept(&self) -> &Dice {
+        &self.kept
+    }
+
+    fn kept_before_roll(&self) -> &[Die] {
+        let kept_from_roll = self.last_event.as_ref().map_or(0, |event| event.kept.len());
+        &self.kept.dice[..self.kept.len().saturating_sub(kept_from_roll)]
+    }
+
+    fn is_kept(&self, index: usize) -> bool {
+        let kept_before = self.kept_before_roll().len();
+        dice_game::is_kept_from(&self.rolled, &self.kept.dice[kept_before..], index)
+    }
+
+    fn outcome(&self) -> Outcome {
+        let total = i16::try_from(self.total).unwrap_or(i16::MAX);
+        if self.has_won() {
+            Outcome::Won(total)
+        } else if self.turns.len() >= MAX_TURNS {
+            Outcome::Lost(total)
+        } else {
+            Outcome::Playing
+        }
+    }
+
+    fn reset(&mut self) {
+        Game::reset(self);
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.small_rng = SmallRng::seed_from_u64(seed);
+        Game::reset(self);
+    }
+
+    fn last_event(&self) -> Option<&Event> {
+        self.last_event.as_ref()
+    }
+
+    fn log(&self) -> &TurnLog {
+        &self.turns
+    }
+}
@@ -0,0 +1,93 @@
+use pico_display::dice::Dice;
+use pico_display::die::FaceValue;
+
+pub const NUMBER_OF_FACES: usize = 6;
+pub const SINGLE_ONE: u16 = 100;
+pub const SINGLE_FIVE: u16 = 50;
+pub const THREE_ONES: u16 = 1000;
+pub const FOUR_OF_A_KIND: u16 = 1000;
+pub const FIVE_OF_A_KIND: u16 = 2000;
+pub const SIX_OF_A_KIND: u16 = 3000;
+pub const STRAIGHT: u16 = 1500;
+pub const THREE_PAIRS: u16 = 1500;
+
+/// The score of the kept dice, `None` if any of them does not score.
+///
+/// Dice of a kind that could be read in more than one way, e.g. four ones as four of a kind or
+/// as three ones and a single one, are scored by the best reading.
+pub fn score(dice: &Dice) -> Option<u16> {
+    let counts = dice.face_counts();
+    let combination = if counts == [1; NUMBER_OF_FACES] {
+        Some(STRAIGHT)
+    } else if counts.iter().filter(|&&count| count == 2).count() == 3 {
+        Some(THREE_PAIRS)
+    } else {
+        None
+    };
+    let by_face = counts
+        .iter()
+        .enumerate()
+        .try_fold(0, |total, (face, &count)| {
+            score_face(face as u8 + 1, count).map(|score| total + score)
+        });
+
+    match (combination, by_face) {
+        (Some(combination), Some(by_face)) => Some(combination.max(by_face)),
+        (combination, by_face) => combination.or(by_face),
+    }
+}
+
+/// All rolled dice that are part of a scoring combination.
+pub fn scoring_dice(rolled: &Dice) -> Dice {
+    let counts = rolled.face_counts();
+    let mut rolled = rolled.clone();
+    if score(&rolled).is_some() {
+        return rolled;
+    }
+    rolled.pick(
+        |value| {
+            counts[value.as_u8() as usize - 1] >= 3
+                || value == FaceValue::One
+                || value == FaceValue::Five
+        },
+        None,
+    )
+}
+
+/// A roll without any scoring dice busts the turn.
+pub fn is_farkle(rolled: &Dice) -> bool {
+    scoring_dice(rolled).is_empty()
+}
+
+fn score_face(face: u8, count: u8) -> Option<u16> {
+    let single = match face {
+        1 => Some(SINGLE_ONE),
+        5 => Some(SINGLE_FIVE),
+        _ => None,
+    };
+    let singles = single.map(|single| single * count as u16);
+    let all_of_a_kind = of_a_kind(face, count);
+    let triple_and_singles = match (of_a_kind(face, 3), single) {
+        (Some(triple), Some(single)) if count > 3 => Some(triple + single * (count as u16 - 3)),
+        _ => None,
+    };
+
+    match count {
+        0 => Some(0),
+        _ => [singles, all_of_a_kind, triple_and_singles]
+            .into_iter()
+            .flatten()
+            .max(),
+    }
+}
+
+fn of_a_kind(face: u8, count: u8) -> Option<u16> {
+    match count {
+        3 if face == 1 => Some(THREE_ONES),
+        3 => Some(face as u16 * 100),
+        4 => Some(FOUR_OF_A_KIND),
+        5 => Some(FIVE_OF_A_KIND),
+        6 => Some(SIX_OF_A_KIND),
+        _ => None,
+    }
+}
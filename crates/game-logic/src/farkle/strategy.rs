@@ -0,0 +1,56 @@
+use pico_display::dice::Dice;
+use pico_display::die::FaceValue;
+
+use super::NUMBER_OF_DICE;
+use super::scoring::scoring_dice;
+
+/// Decides which dice to keep and when to bank the turn.
+pub trait Strategy {
+    /// Returns the dice to keep out of a roll that scores. The kept dice must be taken from
+    /// `rolled` and all of them must score, otherwise the game keeps every scoring die.
+    fn keep(&mut self, rolled: &Dice, turn_score: u16) -> Dice;
+
+    /// Whether to bank `turn_score` instead of rolling the `dice_left` dice, with `needed`
+    /// points missing to the target score.
+    fn bank(&mut self, turn_score: u16, dice_left: u8, needed: u16) -> bool;
+}
+
+/// Keeps every combination along with the ones, but only a single one or five when there is
+/// no combination, and banks once the turn score outweighs the risk of the dice left.
+#[derive(Default, Clone, Copy)]
+pub struct DefaultStrategy;
+
+impl Strategy for DefaultStrategy {
+    fn keep(&mut self, rolled: &Dice, _turn_score: u16) -> Dice {
+        let mut scoring = scoring_dice(rolled);
+        if scoring.len() == rolled.len() {
+            // hot dice
+            return scoring;
+        }
+
+        let counts = scoring.face_counts();
+        let mut kept = scoring.pick(|value| counts[value.as_u8() as usize - 1] >= 3, None);
+        if kept.is_empty() {
+            kept = scoring.pick(|value| value == FaceValue::One, Some(1));
+        } else {
+            kept.append(&mut scoring.pick(|value| value == FaceValue::One, None));
+        }
+        if kept.is_empty() {
+            kept = scoring.pick(|value| value == FaceValue::Five, Some(1));
+        }
+        kept
+    }
+
+    fn bank(&mut self, turn_score: u16, dice_left: u8, needed: u16) -> bool {
+        if turn_score >= needed {
+            return true;
+        }
+        match dice_left {
+            NUMBER_OF_DICE => false,
+            5 => turn_score >= 2000,
+            4 => turn_score >= 1000,
+            3 => turn_score >= 400,
+            _ => turn_score >= 300,
+        }
+    }
+}
@@ -1,3 +1,12 @@
 #![cfg_attr(not(test), no_std)]
+pub mod commitment;
+pub mod dice_game;
+pub mod entropy;
+pub mod farkle;
+pub mod notation;
+pub mod pig;
 pub mod player;
+pub mod session;
+pub mod statistics;
 pub mod two_four_eighteen;
+pub mod yahtzee;
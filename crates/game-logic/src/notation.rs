@@ -0,0 +1,284 @@
+pub mod parser;
+
+use core::fmt;
+use core::str::FromStr;
+use pico_display::dice::Dice;
+use pico_display::polyhedral::{PolyhedralDie, Sides};
+use rand::Rng;
+
+use parser::{ParseError, Parser};
+
+/// The most dice an expression rolls, including the ones added by exploding dice.
+pub const MAX_DICE: usize = 64;
+pub const MAX_TERMS: usize = 8;
+/// A die that matches its reroll condition this often in a row is kept as it is.
+pub const MAX_REROLLS: usize = 100;
+/// Expressions to offer without typing them.
+pub const PRESETS: [&str; 8] = [
+    "1d20", "2d20kh1", "2d20kl1", "1d20+5", "3d6", "4d6kh3", "2d6+3", "1d100",
+];
+
+/// Which faces a reroll or an explosion applies to.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Compare {
+    Equal(u8),
+    AtMost(u8),
+    AtLeast(u8),
+}
+
+impl Compare {
+    pub fn matches(&self, face: u8) -> bool {
+        match *self {
+            Compare::Equal(value) => face == value,
+            Compare::AtMost(value) => face <= value,
+            Compare::AtLeast(value) => face >= value,
+        }
+    }
+}
+
+impl fmt::Display for Compare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Compare::Equal(value) => write!(f, "{value}"),
+            Compare::AtMost(value) => write!(f, "<{value}"),
+            Compare::AtLeast(value) => write!(f, ">{value}"),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Keep {
+    Highest(u8),
+    Lowest(u8),
+    DropHighest(u8),
+    DropLowest(u8),
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Reroll {
+    pub compare: Compare,
+    /// Rerolls at most once instead of until the die no longer matches.
+    pub once: bool,
+}
+
+/// A number of dice with the same sides, e.g. "4d6kh3".
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct DiceTerm {
+    pub count: u8,
+    pub sides: Sides,
+    pub keep: Option<Keep>,
+    pub explode: Option<Compare>,
+    pub reroll: Option<Reroll>,
+}
+
+impl fmt::Display for DiceTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}d{}", self.count, self.sides.count())?;
+        if let Some(reroll) = self.reroll {
+            let once = if reroll.once { "o" } else { "" };
+            write!(f, "r{once}{}", reroll.compare)?;
+        }
+        if let Some(explode) = self.explode {
+            write!(f, "!{explode}")?;
+        }
+        match self.keep {
+            Some(Keep::Highest(n)) => write!(f, "kh{n}"),
+            Some(Keep::Lowest(n)) => write!(f, "kl{n}"),
+            Some(Keep::DropHighest(n)) => write!(f, "dh{n}"),
+            Some(Keep::DropLowest(n)) => write!(f, "dl{n}"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Term {
+    Dice { negative: bool, term: DiceTerm },
+    Constant { negative: bool, constant: u16 },
+}
+
+/// A parsed dice expression such as "2d20kl1+4", to be rolled any number of times.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct Expression {
+    terms: [Option<Term>; MAX_TERMS],
+    len: u8,
+}
+
+impl Expression {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Parser::new(input).expression()
+    }
+
+    pub fn terms(&self) -> impl Iterator<Item = &Term> {
+        self.terms.iter().flatten()
+    }
+
+    /// Rolls every term with the rng, which can be any source of randomness such as the game's
+    /// `SmallRng`.
+    pub fn roll<R>(&self, rng: &mut R) -> Roll
+    where
+        R: Rng + ?Sized,
+    {
+        let mut roll = Roll {
+            total: 0,
+            dice: Dice::empty(),
+            dropped: 0,
+        };
+        for term in self.terms() {
+            let (negative, value) = match *term {
+                Term::Constant { negative, constant } => (negative, constant as i32),
+                Term::Dice { negative, term } => (negative, roll.roll_term(&term, rng)),
+            };
+            roll.total += if negative { -value } else { value };
+        }
+        roll
+    }
+
+    fn push(&mut self, term: Term) -> bool {
+        match self.terms.get_mut(self.len as usize) {
+            Some(slot) => {
+                *slot = Some(term);
+                self.len += 1;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Expression::parse(input)
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, term) in self.terms().enumerate() {
+            let negative = match term {
+                Term::Dice { negative, .. } | Term::Constant { negative, .. } => *negative,
+            };
+            match (index, negative) {
+                (_, true) => f.write_str("-")?,
+                (0, false) => {}
+                (_, false) => f.write_str("+")?,
+            }
+            match term {
+                Term::Dice { term, .. } => write!(f, "{term}")?,
+                Term::Constant { constant, .. } => write!(f, "{constant}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The outcome of rolling an expression: the total along with every die that was rolled, to
+/// be drawn with `Dice::draw`.
+#[derive(Clone, PartialEq)]
+pub struct Roll {
+    pub total: i32,
+    pub dice: Dice<PolyhedralDie>,
+    dropped: u64,
+}
+
+impl Roll {
+    /// Whether the die counts towards the total, i.e. was not dropped by keep or drop.
+    pub fn is_kept(&self, index: usize) -> bool {
+        index < self.dice.len() && self.dropped & (1 << index) == 0
+    }
+
+    pub fn kept(&self) -> impl Iterator<Item = &PolyhedralDie> {
+        self.dice
+            .dice
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| self.is_kept(index))
+            .map(|(_, die)| die)
+    }
+
+    /// Rolls the term's dice, rerolls and explodes them, drops the ones not kept and
+    /// returns the sum of the kept ones.
+    fn roll_term<R>(&mut self, term: &DiceTerm, rng: &mut R) -> i32
+    where
+        R: Rng + ?Sized,
+    {
+        let first = self.dice.len();
+        let mut rolled = Dice::roll_sides(term.sides, term.count as u32, rng);
+        self.dice.append(&mut rolled);
+
+        let mut index = first;
+        while index < self.dice.len() {
+            if let Some(reroll) = term.reroll {
+                let die = &mut self.dice.dice[index];
+                for _ in 0..MAX_REROLLS {
+                    if !reroll.compare.matches(die.value) {
+                        break;
+                    }
+                    *die = rng.sample(term.sides);
+                    if reroll.once {
+                        break;
+                    }
+                }
+            }
+            let explodes = term
+                .explode
+                .is_some_and(|explode| explode.matches(self.dice.dice[index].value));
+            if explodes && self.dice.len() < MAX_DICE {
+                self.dice.push(rng.sample(term.sides));
+            }
+            index += 1;
+        }
+
+        let pool = first..self.dice.len();
+        if let Some(keep) = term.keep {
+            let (drop_highest, n) = match keep {
+                Keep::Highest(n) => (false, pool.len().saturating_sub(n as usize)),
+                Keep::Lowest(n) => (true, pool.len().saturating_sub(n as usize)),
+                Keep::DropHighest(n) => (true, n as usize),
+                Keep::DropLowest(n) => (false, n as usize),
+            };
+            for _ in 0..n {
+                let candidates = pool.clone().filter(|&index| self.is_kept(index));
+                let value = |index: &usize| self.dice.dice[*index].value;
+                let dropped = if drop_highest {
+                    candidates.max_by_key(value)
+                } else {
+                    candidates.min_by_key(value)
+                };
+                if let Some(index) = dropped {
+                    self.dropped |= 1 << index;
+                }
+            }
+        }
+
+        pool.filter(|&index| self.is_kept(index))
+            .map(|index| self.dice.dice[index].value as i32)
+            .sum()
+    }
+}
+
+impl fmt::Display for Roll {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, die) in self.dice.dice.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            if self.is_kept(index) {
+                write!(f, "{}", die.value)?;
+            } else {
+                write!(f, "({})", die.value)?;
+            }
+        }
+        write!(f, " = {}", self.total)
+    }
+}
+
+/// Parses and rolls the expression in one go.
+pub fn roll<R>(input: &str, rng: &mut R) -> Result<Roll, ParseError>
+where
+    R: Rng + ?Sized,
+{
+    Expression::parse(input).map(|expression| expression.roll(rng))
+}
@@ -0,0 +1,306 @@
+use core::fmt;
+use pico_display::polyhedral::Sides;
+
+use super::{Compare, DiceTerm, Expression, Keep, MAX_DICE, Reroll, Term};
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ParseErrorKind {
+    Empty,
+    UnexpectedEnd,
+    UnexpectedCharacter(char),
+    ExpectedNumber,
+    NumberTooLarge,
+    /// Only the sides of `Sides` can be rolled and drawn.
+    UnsupportedSides(u16),
+    NoDice,
+    TooManyDice,
+    TooManyTerms,
+    DuplicateModifier,
+    /// Keeping or dropping more dice than are rolled, or keeping none.
+    KeepOutOfRange,
+    /// Exploding or rerolling on every face would never end.
+    EveryFaceMatches,
+}
+
+/// What went wrong and where, `position` being the byte offset into the input.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub position: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => f.write_str("empty expression")?,
+            ParseErrorKind::UnexpectedEnd => f.write_str("unexpected end")?,
+            ParseErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected '{c}'")?,
+            ParseErrorKind::ExpectedNumber => f.write_str("expected a number")?,
+            ParseErrorKind::NumberTooLarge => f.write_str("number too large")?,
+            ParseErrorKind::UnsupportedSides(sides) => write!(f, "no d{sides}")?,
+            ParseErrorKind::NoDice => f.write_str("no dice to roll")?,
+            ParseErrorKind::TooManyDice => write!(f, "more than {MAX_DICE} dice")?,
+            ParseErrorKind::TooManyTerms => f.write_str("too many terms")?,
+            ParseErrorKind::DuplicateModifier => f.write_str("duplicate modifier")?,
+            ParseErrorKind::KeepOutOfRange => f.write_str("cannot keep that many dice")?,
+            ParseErrorKind::EveryFaceMatches => f.write_str("matches every face")?,
+        }
+        write!(f, " at {}", self.position)
+    }
+}
+
+pub(super) struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+    dice: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            position: 0,
+            dice: 0,
+        }
+    }
+
+    /// expression = ["+" | "-"] term {("+" | "-") term}
+    pub(super) fn expression(&mut self) -> Result<Expression, ParseError> {
+        let mut expression = Expression::default();
+        self.skip_whitespace();
+        if self.peek().is_none() {
+            return Err(self.error(ParseErrorKind::Empty));
+        }
+
+        let mut negative = self.sign();
+        loop {
+            self.skip_whitespace();
+            let start = self.position;
+            let term = self.term(negative)?;
+            if !expression.push(term) {
+                return Err(self.error_at(ParseErrorKind::TooManyTerms, start));
+            }
+
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Ok(expression),
+                Some(b'+' | b'-') => negative = self.sign(),
+                Some(_) => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    /// term = number | [number] "d" (number | "%") {modifier}
+    fn term(&mut self, negative: bool) -> Result<Term, ParseError> {
+        let start = self.position;
+        let count = self.number()?;
+        if !matches!(self.peek(), Some(b'd')) {
+            return match (count, self.peek()) {
+                (Some(constant), _) => Ok(Term::Constant { negative, constant }),
+                (None, Some(_)) => Err(self.unexpected()),
+                (None, None) => Err(self.error(ParseErrorKind::UnexpectedEnd)),
+            };
+        }
+        self.position += 1;
+
+        let count = count.unwrap_or(1);
+        if count == 0 {
+            return Err(self.error_at(ParseErrorKind::NoDice, start));
+        }
+        self.dice += count as usize;
+        if self.dice > MAX_DICE {
+            return Err(self.error_at(ParseErrorKind::TooManyDice, start));
+        }
+        let sides = self.sides()?;
+        let mut term = DiceTerm {
+            count: count as u8,
+            sides,
+            keep: None,
+            explode: None,
+            reroll: None,
+        };
+        self.modifiers(&mut term)?;
+        Ok(Term::Dice { negative, term })
+    }
+
+    fn sides(&mut self) -> Result<Sides, ParseError> {
+        let start = self.position;
+        if self.peek() == Some(b'%') {
+            self.position += 1;
+            return Ok(Sides::D100);
+        }
+        let sides = self.expect_number()?;
+        u8::try_from(sides)
+            .ok()
+            .and_then(Sides::from_count)
+            .ok_or(self.error_at(ParseErrorKind::UnsupportedSides(sides), start))
+    }
+
+    /// modifier = ("k" | "kh" | "kl" | "dh" | "dl") [number] | "!" [compare] | ("r" | "ro") [compare]
+    fn modifiers(&mut self, term: &mut DiceTerm) -> Result<(), ParseError> {
+        loop {
+            let start = self.position;
+            match self.peek() {
+                Some(b'k' | b'd') => {
+                    let keeps = self.peek() == Some(b'k');
+                    self.position += 1;
+                    let highest = match self.peek() {
+                        Some(b'h') => true,
+                        Some(b'l') => false,
+                        _ if keeps => true,
+                        Some(_) => return Err(self.unexpected()),
+                        None => return Err(self.error(ParseErrorKind::UnexpectedEnd)),
+                    };
+                    if matches!(self.peek(), Some(b'h' | b'l')) {
+                        self.position += 1;
+                    }
+                    let number_start = self.position;
+                    let n = self.number()?.unwrap_or(1);
+                    let in_range = if keeps {
+                        (1..=term.count as u16).contains(&n)
+                    } else {
+                        n < term.count as u16
+                    };
+                    if !in_range {
+                        return Err(self.error_at(ParseErrorKind::KeepOutOfRange, number_start));
+                    }
+                    let n = n as u8;
+                    let keep = match (keeps, highest) {
+                        (true, true) => Keep::Highest(n),
+                        (true, false) => Keep::Lowest(n),
+                        (false, true) => Keep::DropHighest(n),
+                        (false, false) => Keep::DropLowest(n),
+                    };
+                    Self::set(&mut term.keep, keep, start)?;
+                }
+                Some(b'!') => {
+                    self.position += 1;
+                    let compare = self.compare(Compare::Equal(term.sides.count()))?;
+                    self.check_compare(compare, term.sides, start)?;
+                    Self::set(&mut term.explode, compare, start)?;
+                }
+                Some(b'r') => {
+                    self.position += 1;
+                    let once = self.peek() == Some(b'o');
+                    if once {
+                        self.position += 1;
+                    }
+                    let compare = self.compare(Compare::Equal(1))?;
+                    if !once {
+                        self.check_compare(compare, term.sides, start)?;
+                    }
+                    Self::set(&mut term.reroll, Reroll { compare, once }, start)?;
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// compare = ["<" | ">"] number
+    fn compare(&mut self, default: Compare) -> Result<Compare, ParseError> {
+        let compare: fn(u8) -> Compare = match self.peek() {
+            Some(b'<') => Compare::AtMost,
+            Some(b'>') => Compare::AtLeast,
+            _ => {
+                return Ok(match self.number()? {
+                    Some(face) => Compare::Equal(face.min(u8::MAX as u16) as u8),
+                    None => default,
+                });
+            }
+        };
+        self.position += 1;
+        let face = self.expect_number()?;
+        Ok(compare(face.min(u8::MAX as u16) as u8))
+    }
+
+    fn check_compare(
+        &self,
+        compare: Compare,
+        sides: Sides,
+        start: usize,
+    ) -> Result<(), ParseError> {
+        if (1..=sides.count()).all(|face| compare.matches(face)) {
+            Err(self.error_at(ParseErrorKind::EveryFaceMatches, start))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn set<T>(modifier: &mut Option<T>, value: T, start: usize) -> Result<(), ParseError> {
+        if modifier.is_some() {
+            return Err(ParseError {
+                kind: ParseErrorKind::DuplicateModifier,
+                position: start,
+            });
+        }
+        *modifier = Some(value);
+        Ok(())
+    }
+
+    fn number(&mut self) -> Result<Option<u16>, ParseError> {
+        let start = self.position;
+        let mut number: Option<u16> = None;
+        while let Some(digit @ b'0'..=b'9') = self.peek() {
+            number = number
+                .unwrap_or_default()
+                .checked_mul(10)
+                .and_then(|number| number.checked_add((digit - b'0') as u16))
+                .map(Some)
+                .ok_or(self.error_at(ParseErrorKind::NumberTooLarge, start))?;
+            self.position += 1;
+        }
+        Ok(number)
+    }
+
+    fn expect_number(&mut self) -> Result<u16, ParseError> {
+        match self.number()? {
+            Some(number) => Ok(number),
+            None => Err(self.error(ParseErrorKind::ExpectedNumber)),
+        }
+    }
+
+    /// Consumes an optional sign, returning whether it is a minus.
+    fn sign(&mut self) -> bool {
+        match self.peek() {
+            Some(b'-') => {
+                self.position += 1;
+                true
+            }
+            Some(b'+') => {
+                self.position += 1;
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    /// The byte at the current position, lower case so that "3D6" reads like "3d6".
+    fn peek(&self) -> Option<u8> {
+        self.input
+            .as_bytes()
+            .get(self.position)
+            .map(|c| c.to_ascii_lowercase())
+    }
+
+    fn unexpected(&self) -> ParseError {
+        let c = self.input[self.position..]
+            .chars()
+            .next()
+            .unwrap_or_default();
+        self.error(ParseErrorKind::UnexpectedCharacter(c))
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        self.error_at(kind, self.position)
+    }
+
+    fn error_at(&self, kind: ParseErrorKind, position: usize) -> ParseError {
+        ParseError { kind, position }
+    }
+}
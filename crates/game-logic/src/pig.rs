@@ -0,0 +1,298 @@
+pub mod strategy;
+
+use core::fmt;
+use pico_display::dice::Dice;
+use pico_display::die::FaceValue;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::dice_game::{DiceGame, Outcome};
+use strategy::{HoldAtN, Strategy};
+
+pub const DEFAULT_TARGET_SCORE: u16 = 100;
+
+/// What the player makes of a break of the beam.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Action {
+    Roll,
+    Bank,
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Player {
+    Human,
+    Computer,
+}
+
+impl Player {
+    fn other(self) -> Self {
+        match self {
+            Player::Human => Player::Computer,
+            Player::Computer => Player::Human,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Turn {
+    Rolled,
+    /// A one was rolled, the turn total is lost.
+    Busted,
+    Banked,
+}
+
+/// A single roll or bank of either player.
+#[derive(PartialEq, Eq, Copy, Clone)]
+pub struct Event {
+    pub player: Player,
+    pub rolled: Option<FaceValue>,
+    pub turn_total: u16,
+    pub score: u16,
+    pub turn: Turn,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.player {
+            Player::Human => "you ",
+            Player::Computer => "computer ",
+        })?;
+        match (self.turn, self.rolled) {
+            (Turn::Banked, _) => write!(f, "banked {}", self.turn_total)?,
+            (Turn::Busted, _) => f.write_str("busted")?,
+            (Turn::Rolled, Some(rolled)) => write!(
+                f,
+                "rolled {}, turn total: {}",
+                rolled.as_u8(),
+                self.turn_total
+            )?,
+            (Turn::Rolled, None) => f.write_str("rolled")?,
+        }
+        write!(f, ", score: {}", self.score)
+    }
+}
+
+/// The banked scores of both players.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct Scoreboard {
+    scores: [u16; 2],
+    turns: u16,
+}
+
+impl Scoreboard {
+    pub fn score(&self, player: Player) -> u16 {
+        self.scores[player.index()]
+    }
+
+    /// The number of finished turns of both players.
+    pub fn turns(&self) -> u16 {
+        self.turns
+    }
+}
+
+impl fmt::Display for Scoreboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "you: {}, computer: {}, turns: {}",
+            self.score(Player::Human),
+            self.score(Player::Computer),
+            self.turns
+        )
+    }
+}
+
+/// Pig against the computer: roll a single die as often as you dare and bank the turn total
+/// before a one wipes it out. The first player to reach the target score wins.
+pub struct Game<S = HoldAtN> {
+    pub small_rng: SmallRng,
+    pub opponent: S,
+    pub target_score: u16,
+    pub rolled: Dice,
+    pub turn_total: u16,
+    player: Player,
+    scoreboard: Scoreboard,
+    last_event: Option<Event>,
+}
+
+impl Game {
+    pub fn new(small_rng: SmallRng, target_score: u16) -> Self {
+        Self::with_opponent(small_rng, target_score, HoldAtN::default())
+    }
+}
+
+impl<S> Game<S>
+where
+    S: Strategy,
+{
+    pub fn with_opponent(small_rng: SmallRng, target_score: u16, opponent: S) -> Self {
+        Self {
+            small_rng,
+            opponent,
+            target_score,
+            rolled: Dice::empty(),
+            turn_total: 0,
+            player: Player::Human,
+            scoreboard: Scoreboard::default(),
+            last_event: None,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.rolled = Dice::empty();
+        self.turn_total = 0;
+        self.player = Player::Human;
+        self.scoreboard = Scoreboard::default();
+        self.last_event = None;
+    }
+
+    /// Plays the action of the human player, or the next move of the computer if it is the
+    /// computer's turn.
+    pub fn play(&mut self, action: Action) {
+        match (self.player, action) {
+            (Player::Computer, _) => self.play_opponent(),
+            (Player::Human, Action::Roll) => self.roll(),
+            (Player::Human, Action::Bank) => self.bank(),
+        }
+    }
+
+    /// Lets the computer roll or bank once.
+    pub fn play_opponent(&mut self) {
+        if self.player != Player::Computer {
+            return;
+        }
+        let banks = self.opponent.bank(
+            self.turn_total,
+            self.score(Player::Computer),
+            self.score(Player::Human),
+            self.target_score,
+        );
+        if banks {
+            self.bank();
+        } else {
+            self.roll();
+        }
+    }
+
+    /// Rolls the die for the current player, a one ends the turn without scoring.
+    pub fn roll(&mut self) {
+        if self.is_over() {
+            return;
+        }
+        let value: FaceValue = self.small_rng.random();
+        self.rolled = Dice::roll(|| value, 1);
+        if value == FaceValue::One {
+            self.turn_total = 0;
+            self.record(Some(value), Turn::Busted);
+            self.end_turn();
+        } else {
+            self.turn_total += value.as_u8() as u16;
+            self.record(Some(value), Turn::Rolled);
+        }
+    }
+
+    /// Adds the turn total to the current player's score and passes the die. Since there is
+    /// nothing to bank before the first roll of a turn, the player rolls instead.
+    pub fn bank(&mut self) {
+        if self.is_over() {
+            return;
+        }
+        if self.turn_total == 0 {
+            self.roll();
+            return;
+        }
+        self.scoreboard.scores[self.player.index()] += self.turn_total;
+        self.record(None, Turn::Banked);
+        if !self.is_over() {
+            self.end_turn();
+        }
+    }
+
+    /// The player whose turn it is.
+    pub fn player(&self) -> Player {
+        self.player
+    }
+
+    pub fn score(&self, player: Player) -> u16 {
+        self.scoreboard.score(player)
+    }
+
+    pub fn winner(&self) -> Option<Player> {
+        [Player::Human, Player::Computer]
+            .into_iter()
+            .find(|&player| self.score(player) >= self.target_score)
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    fn end_turn(&mut self) {
+        self.turn_total = 0;
+        self.scoreboard.turns += 1;
+        self.player = self.player.other();
+    }
+
+    fn record(&mut self, rolled: Option<FaceValue>, turn: Turn) {
+        self.last_event = Some(Event {
+            player: self.player,
+            rolled,
+            turn_total: self.turn_total,
+            score: self.score(self.player),
+            turn,
+        });
+    }
+}
+
+impl<S> DiceGame for Game<S>
+where
+    S: Strategy,
+{
+    type Event = Event;
+    type Log = Scoreboard;
+
+    const LOST_MESSAGE: &'static str = "CPU\nwins!";
+
+    /// Rolls again on the human's turn and lets the computer move on its own.
+    fn roll(&mut self) {
+        self.play(Action::Roll);
+    }
+
+    fn rolled(&self) -> &Dice {
+        &self.rolled
+    }
+
+    fn kept(&self) -> &Dice {
+        &self.rolled
+    }
+
+    fn outcome(&self) -> Outcome {
+        let score = i16::try_from(self.score(Player::Human)).unwrap_or(i16::MAX);
+        match self.winner() {
+            Some(Player::Human) => Outcome::Won(score),
+            Some(Player::Computer) => Outcome::Lost(score),
+            None => Outcome::Playing,
+        }
+    }
+
+    fn reset(&mut self) {
+        Game::reset(self);
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.small_rng = SmallRng::seed_from_u64(seed);
+        Game::reset(self);
+    }
+
+    fn last_event(&self) -> Option<&Event> {
+        self.last_event.as_ref()
+    }
+
+    fn log(&self) -> &Scoreboard {
+        &self.scoreboard
+    }
+}
@@ -0,0 +1,31 @@
+pub const DEFAULT_HOLD_AT: u16 = 20;
+
+/// Decides when the computer banks its turn total.
+pub trait Strategy {
+    /// Whether to bank `turn_total` on top of `score` instead of rolling again, with the
+    /// opponent at `opponent_score` and `target_score` to reach.
+    fn bank(&mut self, turn_total: u16, score: u16, opponent_score: u16, target_score: u16)
+    -> bool;
+}
+
+/// Banks once the turn total reaches `N`, or as soon as banking wins the game.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct HoldAtN(pub u16);
+
+impl Default for HoldAtN {
+    fn default() -> Self {
+        HoldAtN(DEFAULT_HOLD_AT)
+    }
+}
+
+impl Strategy for HoldAtN {
+    fn bank(
+        &mut self,
+        turn_total: u16,
+        score: u16,
+        _opponent_score: u16,
+        target_score: u16,
+    ) -> bool {
+        turn_total >= self.0 || score + turn_total >= target_score
+    }
+}
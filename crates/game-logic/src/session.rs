@@ -0,0 +1,187 @@
+use core::fmt;
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 6;
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum SessionError {
+    TooFewPlayers,
+    TooManyPlayers,
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionError::TooFewPlayers => write!(f, "at least {MIN_PLAYERS} players needed"),
+            SessionError::TooManyPlayers => write!(f, "at most {MAX_PLAYERS} players allowed"),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum RoundState {
+    Playing,
+    /// The highest score was shared, the tied players play another turn each.
+    RollOff,
+    /// The round was won by the player with the given index.
+    Won(usize),
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Player {
+    pub name: &'static str,
+    /// The score of the current round, if the player already had a turn.
+    pub score: Option<i16>,
+    pub rounds_won: u8,
+    contending: bool,
+}
+
+impl Player {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            score: None,
+            rounds_won: 0,
+            contending: true,
+        }
+    }
+
+    fn is_waiting(&self) -> bool {
+        self.contending && self.score.is_none()
+    }
+}
+
+/// Rounds of 2 to 6 players taking turns, where the highest score wins the round.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Session {
+    players: [Player; MAX_PLAYERS],
+    number_of_players: usize,
+    first_player: usize,
+    current_player: usize,
+    round: u16,
+    state: RoundState,
+}
+
+impl Session {
+    pub fn new(names: &[&'static str]) -> Result<Self, SessionError> {
+        if names.len() < MIN_PLAYERS {
+            return Err(SessionError::TooFewPlayers);
+        }
+        if names.len() > MAX_PLAYERS {
+            return Err(SessionError::TooManyPlayers);
+        }
+        let mut players = [Player::new(""); MAX_PLAYERS];
+        for (player, &name) in players.iter_mut().zip(names) {
+            *player = Player::new(name);
+        }
+        Ok(Self {
+            players,
+            number_of_players: names.len(),
+            first_player: 0,
+            current_player: 0,
+            round: 1,
+            state: RoundState::Playing,
+        })
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players[..self.number_of_players]
+    }
+
+    pub fn round(&self) -> u16 {
+        self.round
+    }
+
+    pub fn state(&self) -> RoundState {
+        self.state
+    }
+
+    /// The index of the player whose turn it is, `None` once the round is won.
+    pub fn current_player(&self) -> Option<usize> {
+        match self.state {
+            RoundState::Won(_) => None,
+            _ => Some(self.current_player),
+        }
+    }
+
+    pub fn winner(&self) -> Option<&Player> {
+        match self.state {
+            RoundState::Won(winner) => Some(&self.players[winner]),
+            _ => None,
+        }
+    }
+
+    /// Records the final score of the current player's turn and passes the turn on. Recording
+    /// a score after a won round starts the next round first.
+    pub fn record(&mut self, score: i16) -> RoundState {
+        if let RoundState::Won(_) = self.state {
+            self.next_round();
+        }
+        self.players[self.current_player].score = Some(score);
+
+        match self.next_waiting_player(self.current_player) {
+            Some(player) => self.current_player = player,
+            None => self.finish_turns(),
+        }
+        self.state
+    }
+
+    /// Clears the scores and lets the next player start the new round.
+    pub fn next_round(&mut self) {
+        for player in self.players.iter_mut() {
+            player.score = None;
+            player.contending = true;
+        }
+        self.round += 1;
+        self.first_player = (self.first_player + 1) % self.number_of_players;
+        self.current_player = self.first_player;
+        self.state = RoundState::Playing;
+    }
+
+    fn finish_turns(&mut self) {
+        let best_score = self
+            .players()
+            .iter()
+            .filter(|player| player.contending)
+            .filter_map(|player| player.score)
+            .max();
+
+        let mut number_of_best = 0;
+        for player in self.players[..self.number_of_players].iter_mut() {
+            if player.contending && player.score == best_score {
+                number_of_best += 1;
+            } else {
+                player.contending = false;
+            }
+        }
+
+        if number_of_best == 1 {
+            let winner = turn_order(self.first_player, self.number_of_players)
+                .find(|&player| self.players[player].contending);
+            if let Some(winner) = winner {
+                self.players[winner].rounds_won += 1;
+                self.state = RoundState::Won(winner);
+            }
+        } else {
+            for player in self.players.iter_mut().filter(|player| player.contending) {
+                player.score = None;
+            }
+            self.state = RoundState::RollOff;
+            if let Some(player) = turn_order(self.first_player, self.number_of_players)
+                .find(|&player| self.players[player].is_waiting())
+            {
+                self.current_player = player;
+            }
+        }
+    }
+
+    fn next_waiting_player(&self, player: usize) -> Option<usize> {
+        (1..self.number_of_players)
+            .map(|offset| (player + offset) % self.number_of_players)
+            .find(|&player| self.players[player].is_waiting())
+    }
+}
+
+fn turn_order(first_player: usize, number_of_players: usize) -> impl Iterator<Item = usize> {
+    (0..number_of_players).map(move |offset| (first_player + offset) % number_of_players)
+}
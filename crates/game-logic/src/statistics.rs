@@ -0,0 +1,148 @@
+use core::fmt;
+use pico_display::die::FaceValue;
+use rand::Rng;
+
+pub const NUMBER_OF_FACES: usize = 6;
+/// Enough rolls for every face to be expected ten thousand times.
+pub const DEFAULT_ROLLS: u32 = 60_000;
+/// Fewer rolls than this prove nothing either way.
+pub const MIN_ROLLS: u32 = 600;
+/// The chi-square value a fair die exceeds once in a thousand tests, at five degrees of
+/// freedom.
+pub const CHI_SQUARE_CRITICAL_VALUE: f64 = 20.515;
+/// How many standard deviations the serial correlation of a fair die may be away from zero.
+/// For n rolls the standard deviation is about one over the square root of n.
+pub const SERIAL_CORRELATION_DEVIATIONS: f64 = 4.0;
+
+/// Collects rolls one at a time, so that the rolls themselves need not be stored.
+#[derive(Clone, Debug, Default)]
+pub struct FaceStatistics {
+    counts: [u32; NUMBER_OF_FACES],
+    first: Option<u8>,
+    previous: Option<u8>,
+    sum: u64,
+    sum_of_squares: u64,
+    sum_of_products: u64,
+}
+
+impl FaceStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, face: FaceValue) {
+        let value = face.as_u8();
+        self.counts[value as usize - 1] += 1;
+        self.sum += value as u64;
+        self.sum_of_squares += (value * value) as u64;
+        if let Some(previous) = self.previous {
+            self.sum_of_products += (previous * value) as u64;
+        }
+        self.first.get_or_insert(value);
+        self.previous = Some(value);
+    }
+
+    pub fn rolls(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// How often each face was rolled, ones first.
+    pub fn counts(&self) -> &[u32; NUMBER_OF_FACES] {
+        &self.counts
+    }
+
+    /// Pearson's chi-square statistic of the counts against a uniform distribution.
+    pub fn chi_square(&self) -> f64 {
+        let rolls = self.rolls();
+        if rolls == 0 {
+            return 0.0;
+        }
+        let expected = rolls as f64 / NUMBER_OF_FACES as f64;
+        self.counts
+            .iter()
+            .map(|&count| {
+                let deviation = count as f64 - expected;
+                deviation * deviation / expected
+            })
+            .sum()
+    }
+
+    /// Knuth's serial correlation coefficient of every roll with the next one, the last roll
+    /// being followed by the first. Close to zero when the rolls are independent, one when
+    /// every roll is the same.
+    pub fn serial_correlation(&self) -> f64 {
+        let (Some(first), Some(last)) = (self.first, self.previous) else {
+            return 0.0;
+        };
+        let n = self.rolls() as f64;
+        let sum = self.sum as f64;
+        let sum_of_products = (self.sum_of_products + (last * first) as u64) as f64;
+        let denominator = n * self.sum_of_squares as f64 - sum * sum;
+        if denominator == 0.0 {
+            return 1.0;
+        }
+        (n * sum_of_products - sum * sum) / denominator
+    }
+
+    pub fn report(&self) -> Fairness {
+        Fairness {
+            rolls: self.rolls(),
+            chi_square: self.chi_square(),
+            serial_correlation: self.serial_correlation(),
+        }
+    }
+}
+
+/// The outcome of a self-test, the evidence that the dice are not rigged.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Fairness {
+    pub rolls: u32,
+    pub chi_square: f64,
+    pub serial_correlation: f64,
+}
+
+impl Fairness {
+    /// Whether every face came up about equally often.
+    pub fn is_uniform(&self) -> bool {
+        self.rolls >= MIN_ROLLS && self.chi_square < CHI_SQUARE_CRITICAL_VALUE
+    }
+
+    /// Whether a roll says nothing about the next one.
+    pub fn is_independent(&self) -> bool {
+        let correlation = self.serial_correlation;
+        self.rolls >= MIN_ROLLS
+            && correlation * correlation * (self.rolls as f64)
+                < SERIAL_CORRELATION_DEVIATIONS * SERIAL_CORRELATION_DEVIATIONS
+    }
+
+    pub fn passes(&self) -> bool {
+        self.is_uniform() && self.is_independent()
+    }
+}
+
+impl fmt::Display for Fairness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = |passes| if passes { "pass" } else { "fail" };
+        write!(
+            f,
+            "{} rolls, chi-square: {:.2} ({}), serial correlation: {:.4} ({})",
+            self.rolls,
+            self.chi_square,
+            verdict(self.is_uniform()),
+            self.serial_correlation,
+            verdict(self.is_independent())
+        )
+    }
+}
+
+/// Rolls the faces through the same `Distribution` as the games and tests them.
+pub fn self_test<R>(rng: &mut R, rolls: u32) -> Fairness
+where
+    R: Rng + ?Sized,
+{
+    let mut statistics = FaceStatistics::new();
+    for _ in 0..rolls {
+        statistics.record(rng.random());
+    }
+    statistics.report()
+}
@@ -1,9 +1,24 @@
+pub mod log;
+pub mod optimal;
+pub mod pick;
+pub mod rules;
+pub mod simulation;
+pub mod solver;
+pub mod strategy;
+
 use core::fmt;
 use core::ops::Sub;
 use pico_display::dice::Dice;
 use pico_display::die::{Die, FaceValue};
-use rand::Rng;
 use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::dice_game::{self, DiceGame, Outcome};
+
+use log::{GameLog, RollEvent};
+use pick::{PickError, Selection};
+use rules::Rules;
+use strategy::{DefaultStrategy, Strategy};
 
 impl fmt::Display for NumberOfDice {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -14,6 +29,8 @@ impl fmt::Display for NumberOfDice {
             NumberOfDice::Three => "Three",
             NumberOfDice::Four => "Four",
             NumberOfDice::Five => "Five",
+            NumberOfDice::Six => "Six",
+            NumberOfDice::Seven => "Seven",
         })
     }
 }
@@ -26,6 +43,8 @@ pub enum NumberOfDice {
     Three,
     Four,
     Five,
+    Six,
+    Seven,
 }
 
 impl NumberOfDice {
@@ -37,6 +56,8 @@ impl NumberOfDice {
             NumberOfDice::Three => 3,
             NumberOfDice::Four => 4,
             NumberOfDice::Five => 5,
+            NumberOfDice::Six => 6,
+            NumberOfDice::Seven => 7,
         }
     }
 
@@ -47,6 +68,8 @@ impl NumberOfDice {
             3 => NumberOfDice::Three,
             4 => NumberOfDice::Four,
             5 => NumberOfDice::Five,
+            6 => NumberOfDice::Six,
+            7 => NumberOfDice::Seven,
             _ => NumberOfDice::Zero,
         }
     }
@@ -60,52 +83,79 @@ impl Sub<u8> for NumberOfDice {
     }
 }
 
-pub struct Game {
+pub struct Game<S = DefaultStrategy> {
     pub dice_left: NumberOfDice,
     pub small_rng: SmallRng,
     pub picked: Dice,
     pub rolled: Dice,
+    pub strategy: S,
+    pub log: GameLog,
+    pub rules: Rules,
 }
 
 impl Game {
-    pub fn new(small_rng: SmallRng) -> Self {
+    pub fn new(small_rng: SmallRng, rules: Rules) -> Self {
+        Self::with_strategy(small_rng, rules, DefaultStrategy)
+    }
+
+    /// Creates a game whose log records `seed`, so that it can be played again from the seed.
+    pub fn from_seed(seed: u64, rules: Rules) -> Self {
+        let mut game = Self::new(SmallRng::seed_from_u64(seed), rules);
+        game.log = GameLog::new(Some(seed));
+        game
+    }
+}
+
+impl<S> Game<S>
+where
+    S: Strategy,
+{
+    pub fn with_strategy(small_rng: SmallRng, rules: Rules, strategy: S) -> Self {
         Self {
-            dice_left: NumberOfDice::Five,
+            dice_left: rules.number_of_dice,
             small_rng,
             picked: Dice::empty(),
             rolled: Dice::empty(),
+            strategy,
+            log: GameLog::new(None),
+            rules,
         }
     }
 
-    pub fn reset(&mut self) {
-        self.dice_left = NumberOfDice::Five;
+    /// Clears the dice and the log, leaving the rng as it is.
+    fn clear(&mut self) {
+        self.dice_left = self.rules.number_of_dice;
         self.picked = Dice::empty();
         self.rolled = Dice::empty();
+        self.log = GameLog::new(None);
+    }
+
+    /// Starts a new game with dice rolled from `seed`.
+    pub fn reseed(&mut self, seed: u64) {
+        self.clear();
+        self.small_rng = SmallRng::seed_from_u64(seed);
+        self.log = GameLog::new(Some(seed));
     }
 
     pub fn roll(&mut self) {
-        if self.dice_left == NumberOfDice::Zero {
+        if !self.is_awaiting_pick() && self.throw().is_err() {
             return;
         }
-        let face_value = || self.small_rng.random();
-        let mut rolled = Dice::roll(face_value, self.dice_left.as_u8() as u32);
-        let initially_rolled = Dice::from(rolled.dice.clone());
+        let kept = self
+            .strategy
+            .pick(&self.rules, &self.rolled, &self.picked, self.dice_left);
+        let kept = self.qualifiers_first(kept);
 
+        let mut rolled = self.rolled.clone();
+        let picked_before_roll = self.picked.len();
         let mut picked = Dice::empty();
         picked.append(&mut self.picked);
-
-        if !has_four(&picked) {
-            picked.append(&mut rolled.pick(|value| value == FaceValue::Four, Some(1)));
-        }
-        if !has_two(&picked) {
-            picked.append(&mut rolled.pick(|value| value == FaceValue::Two, Some(1)));
-        }
-        if !has_fish(&picked) {
-            let pick_gte = self.pick_gte_when_no_fish(&rolled, &picked);
-            picked.append(&mut rolled.pick(|value| value >= pick_gte, None));
+        // only dice that were actually rolled can be kept
+        for die in kept.dice.iter() {
+            picked.append(&mut rolled.pick(|value| value == die.value, Some(1)));
         }
         // at least one die needs to be picked
-        if !self.did_new_pick(&picked) {
+        if self.rules.dice_left(&picked) == self.dice_left {
             let pic = match rolled.max() {
                 Some(die) => die,
                 // there must be a max value since dice were rolled
@@ -114,77 +164,172 @@ impl Game {
             picked.push(pic);
         }
 
-        self.dice_left = dice_left(&picked);
+        let kept = Dice::from(picked.dice[picked_before_roll..].to_vec());
+        self.dice_left = self.rules.dice_left(&picked);
         self.picked = picked;
-        self.rolled = initially_rolled;
+        self.record(&kept);
     }
 
-    fn pick_gte_when_no_fish(&self, rolled: &Dice, picked: &Dice) -> FaceValue {
-        if can_win(picked) && (has_six(rolled) || self.did_new_pick(picked)) {
-            FaceValue::Six
-        } else {
-            let dice_left = dice_left(picked);
-            if dice_left <= NumberOfDice::Two
-                || count(rolled, |value| value >= FaceValue::Five) >= 2
-                || count(rolled, |value| value >= FaceValue::Four) >= 3
-            {
-                // The expection value for rolling a die is 3.5.
-                FaceValue::Four
-            } else {
-                // The expection value for rolling a die with the option to re-roll it is 4.25.
-                // 4.25 = (4 + 5 + 6) / 3 * 1/2 + 3.5 * 1/2
-                FaceValue::Five
-            }
+    /// Rolls the dice left without picking any, leaving the choice to the player.
+    pub fn throw(&mut self) -> Result<(), PickError> {
+        if self.dice_left == NumberOfDice::Zero {
+            return Err(PickError::NoDiceLeft);
+        }
+        if self.is_awaiting_pick() {
+            return Err(PickError::AlreadyRolled);
         }
+        let face_value = || self.small_rng.random();
+        self.rolled = Dice::roll(face_value, self.dice_left.as_u8() as u32);
+        Ok(())
     }
 
-    fn did_new_pick(&self, picked: &Dice) -> bool {
-        self.dice_left > dice_left(picked)
+    /// Keeps the selected dice of the last throw. A pick that makes a fish certain is rejected
+    /// unless every pick would have, as the qualifiers are not among the dice rolled.
+    pub fn keep(&mut self, selection: &Selection) -> Result<(), PickError> {
+        if !self.is_awaiting_pick() {
+            return Err(PickError::NotRolled);
+        }
+        if selection.number_of_dice() != self.rolled.len() {
+            return Err(PickError::InvalidSelection);
+        }
+        // at least one die needs to be picked
+        if !selection.has_selected() {
+            return Err(PickError::NothingPicked);
+        }
+
+        let mut kept = Dice::empty();
+        for index in selection.selected() {
+            kept.push(self.rolled.dice[index]);
+        }
+        let kept = self.qualifiers_first(kept);
+        let mut picked = self.picked.clone();
+        picked.append(&mut kept.clone());
+        if !self.rules.can_qualify(&picked) && self.rules.can_qualify(&self.qualifying_pick()) {
+            return Err(PickError::QualifiersOutOfReach);
+        }
+        self.picked = picked;
+        self.dice_left = self.rules.dice_left(&self.picked);
+        self.record(&kept);
+        Ok(())
     }
 
-    pub fn score(&self) -> i8 {
-        if has_fish(&self.picked) {
-            return -1;
+    /// The kept dice with the qualifiers among them moved to the front in the order of the
+    /// rules, so that ordered qualifiers kept in the same roll count whichever was selected
+    /// first.
+    fn qualifiers_first(&self, kept: Dice) -> Dice {
+        if !self.rules.ordered_qualifiers {
+            return kept;
+        }
+        let mut rest = kept;
+        let mut kept = self.rules.pick_qualifiers(&self.picked, &mut rest);
+        kept.append(&mut rest);
+        kept
+    }
+
+    /// The picked dice after keeping as many of the missing qualifiers as were rolled, or any
+    /// single die if none were, the pick that leaves the most dice for the qualifiers.
+    fn qualifying_pick(&self) -> Dice {
+        let mut rolled = self.rolled.clone();
+        let mut picked = self.picked.clone();
+        picked.append(&mut self.rules.pick_qualifiers(&self.picked, &mut rolled));
+        if picked.len() == self.picked.len() {
+            picked.append(&mut rolled.pick(|_| true, Some(1)));
         }
-        self.picked
-            .dice
-            .iter()
-            .fold(0, |acc, &die| acc + die.value.as_u8()) as i8
-            - NumberOfDice::Four.as_u8() as i8
-            - NumberOfDice::Two.as_u8() as i8
+        picked
+    }
+
+    /// Whether the dice were thrown but none of them were picked yet.
+    pub fn is_awaiting_pick(&self) -> bool {
+        self.dice_left > NumberOfDice::Zero && self.rolled.len() == self.dice_left.as_u8() as usize
+    }
+
+    /// How many of the picked dice were kept from the last roll, none while it awaits a pick.
+    fn kept_from_roll(&self) -> usize {
+        match self.log.events().last() {
+            Some(event) if !self.is_awaiting_pick() => event.kept().len(),
+            _ => 0,
+        }
+    }
+
+    pub fn score(&self) -> i8 {
+        self.rules.score(&self.picked)
     }
 
     pub fn has_fish(&self) -> bool {
-        has_fish(&self.picked)
+        self.rules.has_fish(&self.picked)
     }
 
     pub fn has_won(&self) -> bool {
-        self.score() == 18
+        self.score() == self.rules.winning_score
     }
-}
 
-fn can_win(picked_dice: &Dice) -> bool {
-    let mut count_4 = 0;
-    let mut count_2 = 0;
-    picked_dice.dice.iter().all(|&x| match x.value {
-        FaceValue::Four => {
-            count_4 += 1;
-            count_4 <= 1
-        }
-        FaceValue::Two => {
-            count_2 += 1;
-            count_2 <= 1
-        }
-        FaceValue::Six => true,
-        _ => false,
-    })
+    /// Adds the last roll to the log. A game has at most as many rolls as dice, so the log
+    /// never runs full.
+    fn record(&mut self, kept: &Dice) {
+        let _ = RollEvent::new(&self.rolled, kept, self.dice_left.as_u8(), self.score())
+            .and_then(|event| self.log.push(event));
+    }
 }
 
-fn dice_left(picked: &Dice) -> NumberOfDice {
-    NumberOfDice::Five - picked.len() as u8
-}
-fn has_fish(dice: &Dice) -> bool {
-    !(has_four(dice) && has_two(dice))
+impl<S> DiceGame for Game<S>
+where
+    S: Strategy,
+{
+    type Event = RollEvent;
+    type Log = GameLog;
+
+    const LOST_MESSAGE: &'static str = "Fish!";
+
+    fn roll(&mut self) {
+        Game::roll(self);
+    }
+
+    fn rolled(&self) -> &Dice {
+        &self.rolled
+    }
+
+    fn kept(&self) -> &Dice {
+        &self.picked
+    }
+
+    fn kept_before_roll(&self) -> &[Die] {
+        let kept_before = self.picked.len() - self.kept_from_roll();
+        &self.picked.dice[..kept_before]
+    }
+
+    fn is_kept(&self, index: usize) -> bool {
+        let kept_before = self.kept_before_roll().len();
+        dice_game::is_kept_from(&self.rolled, &self.picked.dice[kept_before..], index)
+    }
+
+    fn outcome(&self) -> Outcome {
+        let score = self.score() as i16;
+        if self.dice_left > NumberOfDice::Zero {
+            Outcome::Playing
+        } else if self.has_fish() {
+            Outcome::Lost(score)
+        } else if self.has_won() {
+            Outcome::Won(score)
+        } else {
+            Outcome::Over(score)
+        }
+    }
+
+    fn reset(&mut self) {
+        self.clear();
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        Game::reseed(self, seed);
+    }
+
+    fn last_event(&self) -> Option<&RollEvent> {
+        self.log.events().last()
+    }
+
+    fn log(&self) -> &GameLog {
+        &self.log
+    }
 }
 
 fn has_two(dice: &Dice) -> bool {
@@ -0,0 +1,269 @@
+use core::fmt;
+use pico_display::dice::Dice;
+use pico_display::die::{Die, FaceValue};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use super::rules::Rules;
+use super::{Game, NumberOfDice};
+
+/// Faces are packed with three bits each into 24 bits.
+const MAX_DICE: usize = 8;
+const BITS_PER_FACE: usize = 3;
+const FACE_MASK: u32 = (1 << BITS_PER_FACE) - 1;
+/// Every roll keeps at least one die, so there are at most as many rolls as dice.
+pub const MAX_ROLLS: usize = MAX_DICE;
+const ENCODED_EVENT_LEN: usize = 8;
+pub const ENCODED_LEN: usize = encoded_len(MAX_ROLLS);
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum LogError {
+    Full,
+    TooManyDice,
+    Malformed,
+    /// A roll does not follow from the rolls before it.
+    Inconsistent,
+}
+
+/// The faces of up to eight dice in the order they were rolled.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+struct PackedFaces(u32);
+
+impl PackedFaces {
+    fn pack(dice: &Dice) -> Result<Self, LogError> {
+        if dice.len() > MAX_DICE {
+            return Err(LogError::TooManyDice);
+        }
+        let packed = dice
+            .dice
+            .iter()
+            .enumerate()
+            .fold(0, |packed, (index, die)| {
+                packed | (die.value.as_u8() as u32) << (index * BITS_PER_FACE)
+            });
+        Ok(Self(packed))
+    }
+
+    fn unpack(&self) -> Result<Dice, LogError> {
+        let mut dice = Dice::empty();
+        for index in 0..MAX_DICE {
+            let value = match (self.0 >> (index * BITS_PER_FACE)) & FACE_MASK {
+                0 => break,
+                1 => FaceValue::One,
+                2 => FaceValue::Two,
+                3 => FaceValue::Three,
+                4 => FaceValue::Four,
+                5 => FaceValue::Five,
+                6 => FaceValue::Six,
+                _ => return Err(LogError::Malformed),
+            };
+            dice.push(Die::new(value));
+        }
+        Ok(dice)
+    }
+}
+
+/// What happened in a single roll.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct RollEvent {
+    rolled: PackedFaces,
+    kept: PackedFaces,
+    /// The number of dice left to roll after the roll.
+    pub dice_left: u8,
+    /// The score after the roll.
+    pub score: i8,
+}
+
+impl RollEvent {
+    pub fn new(rolled: &Dice, kept: &Dice, dice_left: u8, score: i8) -> Result<Self, LogError> {
+        Ok(Self {
+            rolled: PackedFaces::pack(rolled)?,
+            kept: PackedFaces::pack(kept)?,
+            dice_left,
+            score,
+        })
+    }
+
+    pub fn rolled(&self) -> Dice {
+        self.rolled.unpack().unwrap_or(Dice::empty())
+    }
+
+    pub fn kept(&self) -> Dice {
+        self.kept.unpack().unwrap_or(Dice::empty())
+    }
+
+    fn encode(&self) -> [u8; ENCODED_EVENT_LEN] {
+        let rolled = self.rolled.0.to_le_bytes();
+        let kept = self.kept.0.to_le_bytes();
+        [
+            rolled[0],
+            rolled[1],
+            rolled[2],
+            kept[0],
+            kept[1],
+            kept[2],
+            self.dice_left,
+            self.score as u8,
+        ]
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, LogError> {
+        let event = Self {
+            rolled: PackedFaces(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0])),
+            kept: PackedFaces(u32::from_le_bytes([bytes[3], bytes[4], bytes[5], 0])),
+            dice_left: bytes[6],
+            score: bytes[7] as i8,
+        };
+        event.rolled.unpack()?;
+        event.kept.unpack()?;
+        Ok(event)
+    }
+}
+
+impl fmt::Display for RollEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_faces = |f: &mut fmt::Formatter<'_>, dice: Dice| {
+            dice.dice
+                .iter()
+                .try_for_each(|die| write!(f, "{}", die.value.as_u8()))
+        };
+        f.write_str("rolled: ")?;
+        write_faces(f, self.rolled())?;
+        f.write_str(", kept: ")?;
+        write_faces(f, self.kept())?;
+        write!(f, ", dice left: {}, score: {}", self.dice_left, self.score)
+    }
+}
+
+/// The length of the encoded log up to the last roll.
+const fn encoded_len(rolls: usize) -> usize {
+    10 + rolls * ENCODED_EVENT_LEN
+}
+
+/// The rolls of a single game, along with the seed of its dice if known.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct GameLog {
+    pub seed: Option<u64>,
+    events: [RollEvent; MAX_ROLLS],
+    len: u8,
+}
+
+impl GameLog {
+    pub fn new(seed: Option<u64>) -> Self {
+        Self {
+            seed,
+            ..Self::default()
+        }
+    }
+
+    pub fn push(&mut self, event: RollEvent) -> Result<(), LogError> {
+        let slot = self
+            .events
+            .get_mut(self.len as usize)
+            .ok_or(LogError::Full)?;
+        *slot = event;
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn events(&self) -> &[RollEvent] {
+        &self.events[..self.len as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Encodes the log as the seed flag, the seed, the number of rolls and eight bytes per
+    /// roll.
+    pub fn to_bytes(&self) -> [u8; ENCODED_LEN] {
+        let mut bytes = [0; ENCODED_LEN];
+        bytes[0] = self.seed.is_some() as u8;
+        bytes[1..9].copy_from_slice(&self.seed.unwrap_or_default().to_le_bytes());
+        bytes[9] = self.len;
+        for (chunk, event) in bytes[10..]
+            .chunks_exact_mut(ENCODED_EVENT_LEN)
+            .zip(self.events())
+        {
+            chunk.copy_from_slice(&event.encode());
+        }
+        bytes
+    }
+
+    /// Decodes a log, the bytes after the last roll may be left out.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, LogError> {
+        if bytes.len() < 10
+            || bytes.len() > ENCODED_LEN
+            || bytes[0] > 1
+            || bytes[9] as usize > MAX_ROLLS
+            || bytes.len() < encoded_len(bytes[9] as usize)
+        {
+            return Err(LogError::Malformed);
+        }
+        let mut seed = [0; 8];
+        seed.copy_from_slice(&bytes[1..9]);
+
+        let mut log = Self::new((bytes[0] == 1).then_some(u64::from_le_bytes(seed)));
+        for chunk in bytes[10..]
+            .chunks_exact(ENCODED_EVENT_LEN)
+            .take(bytes[9] as usize)
+        {
+            log.push(RollEvent::decode(chunk)?)?;
+        }
+        Ok(log)
+    }
+
+    /// Rebuilds the game that was played with `rules` after the logged rolls, checking that
+    /// each roll follows from the ones before. If the seed is known, every roll is drawn from
+    /// it again and has to show the logged faces, so the dice of the rebuilt game continue
+    /// where the game left off.
+    pub fn replay(&self, rules: Rules) -> Result<Game, LogError> {
+        let mut game = Game::new(
+            SmallRng::seed_from_u64(self.seed.unwrap_or_default()),
+            rules,
+        );
+        for event in self.events() {
+            let mut rolled = event.rolled();
+            let mut kept = event.kept();
+            if rolled.len() != game.dice_left.as_u8() as usize || kept.is_empty() {
+                return Err(LogError::Inconsistent);
+            }
+            if self.seed.is_some() {
+                let face_value = || game.small_rng.random();
+                if Dice::roll(face_value, game.dice_left.as_u8() as u32) != rolled {
+                    return Err(LogError::Inconsistent);
+                }
+            }
+            for die in kept.dice.iter() {
+                if rolled.pick(|value| value == die.value, Some(1)).is_empty() {
+                    return Err(LogError::Inconsistent);
+                }
+            }
+
+            game.rolled = event.rolled();
+            game.picked.append(&mut kept);
+            game.dice_left = rules.dice_left(&game.picked);
+            if game.dice_left != NumberOfDice::from_u8(event.dice_left)
+                || game.score() != event.score
+            {
+                return Err(LogError::Inconsistent);
+            }
+            game.log.push(*event)?;
+        }
+        game.log.seed = self.seed;
+        Ok(game)
+    }
+}
+
+/// Writes the encoded log up to the last roll as hex.
+impl fmt::Display for GameLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_bytes()[..encoded_len(self.len())]
+            .iter()
+            .try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
@@ -0,0 +1,91 @@
+use pico_display::dice::Dice;
+use pico_display::die::{Die, FaceValue};
+
+use super::rules::Rules;
+use super::solver::{NUMBER_OF_FACES, Objective, Position, ValueTable};
+use super::strategy::{DefaultStrategy, Strategy};
+use super::{NumberOfDice, has_four, has_two};
+
+static EXPECTED_SCORE_TABLE: ValueTable =
+    include!(concat!(env!("OUT_DIR"), "/expected_score_table.rs"));
+static WIN_PROBABILITY_TABLE: ValueTable =
+    include!(concat!(env!("OUT_DIR"), "/win_probability_table.rs"));
+
+/// Returns the table that was precomputed at build time for `objective`.
+pub fn value_table(objective: Objective) -> &'static ValueTable {
+    match objective {
+        Objective::ExpectedScore => &EXPECTED_SCORE_TABLE,
+        Objective::WinProbability => &WIN_PROBABILITY_TABLE,
+    }
+}
+
+pub fn position(picked: &Dice, dice_left: NumberOfDice) -> Position {
+    Position {
+        has_four: has_four(picked),
+        has_two: has_two(picked),
+        dice_left: dice_left.as_u8(),
+        total: picked.dice.iter().map(|die| die.value.as_u8()).sum(),
+    }
+}
+
+/// The expected final score of optimal play from the given position.
+pub fn expected_score(picked: &Dice, dice_left: NumberOfDice) -> f32 {
+    value_table(Objective::ExpectedScore).value(position(picked, dice_left))
+}
+
+/// The probability to score 18 with optimal play from the given position.
+pub fn win_probability(picked: &Dice, dice_left: NumberOfDice) -> f32 {
+    value_table(Objective::WinProbability).value(position(picked, dice_left))
+}
+
+/// Keeps the dice that maximize the objective according to the precomputed tables. The tables
+/// are solved for the standard rules, other rules are played with the default strategy.
+#[derive(Clone, Copy)]
+pub struct OptimalStrategy {
+    objective: Objective,
+}
+
+impl OptimalStrategy {
+    pub fn new(objective: Objective) -> Self {
+        Self { objective }
+    }
+}
+
+impl Default for OptimalStrategy {
+    fn default() -> Self {
+        Self::new(Objective::ExpectedScore)
+    }
+}
+
+impl Strategy for OptimalStrategy {
+    fn pick(
+        &mut self,
+        rules: &Rules,
+        rolled: &Dice,
+        picked: &Dice,
+        dice_left: NumberOfDice,
+    ) -> Dice {
+        if *rules != Rules::TWO_FOUR_EIGHTEEN {
+            return DefaultStrategy.pick(rules, rolled, picked, dice_left);
+        }
+        let kept = value_table(self.objective)
+            .best_keep(position(picked, dice_left), &rolled.face_counts());
+
+        let mut dice = Dice::empty();
+        for (face, &count) in kept.iter().enumerate() {
+            for _ in 0..count {
+                dice.push(Die::new(FACE_VALUES[face]));
+            }
+        }
+        dice
+    }
+}
+
+const FACE_VALUES: [FaceValue; NUMBER_OF_FACES] = [
+    FaceValue::One,
+    FaceValue::Two,
+    FaceValue::Three,
+    FaceValue::Four,
+    FaceValue::Five,
+    FaceValue::Six,
+];
@@ -0,0 +1,79 @@
+use core::fmt;
+
+const MAX_NUMBER_OF_DICE: usize = u8::BITS as usize;
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum PickError {
+    NotRolled,
+    AlreadyRolled,
+    NoDiceLeft,
+    NothingPicked,
+    InvalidSelection,
+    /// The pick leaves fewer dice than qualifiers still missing, while keeping the qualifiers
+    /// of the roll would not have.
+    QualifiersOutOfReach,
+}
+
+impl fmt::Display for PickError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PickError::NotRolled => "Roll before\npicking dice.",
+            PickError::AlreadyRolled => "Pick dice before\nrolling again.",
+            PickError::NoDiceLeft => "No dice left\nto roll.",
+            PickError::NothingPicked => "Keep at least\none die.",
+            PickError::InvalidSelection => "Invalid\nselection.",
+            PickError::QualifiersOutOfReach => "Keep the dice\nto qualify.",
+        })
+    }
+}
+
+/// The dice a player has chosen out of a roll and the die the cursor is at.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct Selection {
+    len: u8,
+    cursor: u8,
+    selected: u8,
+}
+
+impl Selection {
+    pub fn new(number_of_dice: usize) -> Self {
+        Self {
+            len: number_of_dice.min(MAX_NUMBER_OF_DICE) as u8,
+            cursor: 0,
+            selected: 0,
+        }
+    }
+
+    pub fn number_of_dice(&self) -> usize {
+        self.len as usize
+    }
+
+    pub fn has_selected(&self) -> bool {
+        self.selected != 0
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor as usize
+    }
+
+    /// Moves the cursor to the next die, wrapping around after the last one.
+    pub fn next(&mut self) {
+        if self.len > 0 {
+            self.cursor = (self.cursor + 1) % self.len;
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        if self.len > 0 {
+            self.selected ^= 1 << self.cursor;
+        }
+    }
+
+    pub fn is_selected(&self, index: usize) -> bool {
+        index < self.number_of_dice() && self.selected & (1 << index) != 0
+    }
+
+    pub fn selected(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.number_of_dice()).filter(|&index| self.is_selected(index))
+    }
+}
@@ -0,0 +1,198 @@
+use core::fmt;
+use pico_display::dice::Dice;
+use pico_display::die::FaceValue;
+
+use super::{NumberOfDice, count};
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum RulesError {
+    /// There are more qualifiers than dice to roll them with.
+    TooManyQualifiers,
+    /// The winning score is above the score of rolling sixes with every die of the cargo.
+    WinningScoreOutOfReach,
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RulesError::TooManyQualifiers => "more qualifiers than dice",
+            RulesError::WinningScoreOutOfReach => "winning score out of reach",
+        })
+    }
+}
+
+/// The house rules a game is played with.
+///
+/// A game scores the total of the picked dice without the qualifiers, as long as every
+/// qualifier was picked. Otherwise the game is a fish and scores `fish_penalty`.
+#[derive(PartialEq, Eq, Copy, Clone)]
+pub struct Rules {
+    pub qualifiers: &'static [FaceValue],
+    /// Whether a qualifier only counts once the ones before it are picked, in the same roll or
+    /// an earlier one.
+    pub ordered_qualifiers: bool,
+    pub number_of_dice: NumberOfDice,
+    pub winning_score: i8,
+    pub fish_penalty: i8,
+}
+
+impl Rules {
+    /// House rules that can be played to the end and won, unlike some of the rules that can be
+    /// put together from the fields. The qualifiers may be picked in any order.
+    pub fn new(
+        qualifiers: &'static [FaceValue],
+        number_of_dice: NumberOfDice,
+        winning_score: i8,
+        fish_penalty: i8,
+    ) -> Result<Rules, RulesError> {
+        let rules = Rules {
+            qualifiers,
+            ordered_qualifiers: false,
+            number_of_dice,
+            winning_score,
+            fish_penalty,
+        };
+        if qualifiers.len() > number_of_dice.as_u8() as usize {
+            return Err(RulesError::TooManyQualifiers);
+        }
+        if winning_score > rules.max_score() {
+            return Err(RulesError::WinningScoreOutOfReach);
+        }
+        Ok(rules)
+    }
+
+    pub const TWO_FOUR_EIGHTEEN: Rules = Rules {
+        qualifiers: &[FaceValue::Four, FaceValue::Two],
+        ordered_qualifiers: false,
+        number_of_dice: NumberOfDice::Five,
+        winning_score: 18,
+        fish_penalty: -1,
+    };
+
+    /// Ship, captain and crew: a six, then a five and then a four qualify the two dice of the
+    /// cargo, a five picked before the six does not count as the captain.
+    pub const SHIP_CAPTAIN_CREW: Rules = Rules {
+        qualifiers: &[FaceValue::Six, FaceValue::Five, FaceValue::Four],
+        ordered_qualifiers: true,
+        number_of_dice: NumberOfDice::Five,
+        winning_score: 12,
+        fish_penalty: 0,
+    };
+
+    pub const SEVEN_DICE: Rules = Rules {
+        qualifiers: &[FaceValue::Four, FaceValue::Two],
+        ordered_qualifiers: false,
+        number_of_dice: NumberOfDice::Seven,
+        winning_score: 30,
+        fish_penalty: -1,
+    };
+
+    pub fn score(&self, picked: &Dice) -> i8 {
+        if self.has_fish(picked) {
+            return self.fish_penalty;
+        }
+        let total: u8 = picked.dice.iter().map(|die| die.value.as_u8()).sum();
+        let qualifiers: u8 = self.qualifiers.iter().map(|value| value.as_u8()).sum();
+        (total - qualifiers) as i8
+    }
+
+    /// The score of rolling sixes with every die that is not needed as a qualifier, none if
+    /// there are more qualifiers than dice.
+    pub fn max_score(&self) -> i8 {
+        let cargo = (self.number_of_dice.as_u8() as usize).saturating_sub(self.qualifiers.len());
+        (cargo as u8 * FaceValue::Six.as_u8()) as i8
+    }
+
+    /// Whether not every qualifier is among the picked dice.
+    pub fn has_fish(&self, picked: &Dice) -> bool {
+        self.missing_qualifiers(picked).next().is_some()
+    }
+
+    /// The qualifiers that still need to be picked, in the order of the rules.
+    pub fn missing_qualifiers<'a>(
+        &'a self,
+        picked: &'a Dice,
+    ) -> impl Iterator<Item = FaceValue> + 'a {
+        let in_order = self.qualifiers_in_order(picked);
+        self.qualifiers
+            .iter()
+            .enumerate()
+            .filter(move |&(index, &qualifier)| {
+                if self.ordered_qualifiers {
+                    return index >= in_order;
+                }
+                let required = count_qualifier(&self.qualifiers[..=index], qualifier);
+                count(picked, |value| value == qualifier) < required
+            })
+            .map(|(_, &qualifier)| qualifier)
+    }
+
+    /// Takes the missing qualifiers out of `rolled`, in the order of the rules. Ordered
+    /// qualifiers are only taken up to the first one that was not rolled.
+    pub fn pick_qualifiers(&self, picked: &Dice, rolled: &mut Dice) -> Dice {
+        let mut kept = Dice::empty();
+        for qualifier in self.missing_qualifiers(picked) {
+            let mut qualifier = rolled.pick(|value| value == qualifier, Some(1));
+            if qualifier.is_empty() && self.ordered_qualifiers {
+                break;
+            }
+            kept.append(&mut qualifier);
+        }
+        kept
+    }
+
+    /// Whether enough dice are left to roll every qualifier that still needs to be picked.
+    pub fn can_qualify(&self, picked: &Dice) -> bool {
+        self.missing_qualifiers(picked).count() <= self.dice_left(picked).as_u8() as usize
+    }
+
+    /// Whether the picked dice still allow the winning score, i.e. every picked die that is
+    /// not needed as a qualifier is a six.
+    pub fn can_win(&self, picked: &Dice) -> bool {
+        if self.ordered_qualifiers {
+            let mut in_order = 0;
+            return picked.dice.iter().all(|die| {
+                if self.qualifiers.get(in_order) == Some(&die.value) {
+                    in_order += 1;
+                    true
+                } else {
+                    die.value == FaceValue::Six
+                }
+            });
+        }
+        picked.dice.iter().all(|die| {
+            die.value == FaceValue::Six
+                || count(picked, |value| value == die.value)
+                    <= count_qualifier(self.qualifiers, die.value)
+        })
+    }
+
+    pub fn dice_left(&self, picked: &Dice) -> NumberOfDice {
+        self.number_of_dice - picked.len() as u8
+    }
+
+    /// How many of the qualifiers are among the picked dice in the order of the rules, other
+    /// dice picked in between them aside.
+    fn qualifiers_in_order(&self, picked: &Dice) -> usize {
+        picked.dice.iter().fold(0, |in_order, die| {
+            if self.qualifiers.get(in_order) == Some(&die.value) {
+                in_order + 1
+            } else {
+                in_order
+            }
+        })
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::TWO_FOUR_EIGHTEEN
+    }
+}
+
+fn count_qualifier(qualifiers: &[FaceValue], qualifier: FaceValue) -> usize {
+    qualifiers
+        .iter()
+        .filter(|&&value| value == qualifier)
+        .count()
+}
@@ -0,0 +1,146 @@
+use core::fmt;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+
+use super::rules::Rules;
+use super::strategy::{DefaultStrategy, Strategy};
+use super::{Game, NumberOfDice};
+
+/// Enough for the scores of seven dice without qualifiers and a fish penalty of down to -21.
+const HISTOGRAM_LEN: usize = 64;
+
+/// Aggregated outcome of a number of played games.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Statistics {
+    pub games: u32,
+    pub wins: u32,
+    pub fish: u32,
+    pub rolls: u32,
+    min_score: i8,
+    max_score: i8,
+    score_histogram: [u32; HISTOGRAM_LEN],
+}
+
+impl Statistics {
+    fn new(rules: &Rules) -> Self {
+        let min_score = rules.fish_penalty.min(0);
+        let max_score = rules.max_score().min(min_score + HISTOGRAM_LEN as i8 - 1);
+        Self {
+            games: 0,
+            wins: 0,
+            fish: 0,
+            rolls: 0,
+            min_score,
+            max_score,
+            score_histogram: [0; HISTOGRAM_LEN],
+        }
+    }
+
+    fn record<S>(&mut self, game: &Game<S>, rolls: u32)
+    where
+        S: Strategy,
+    {
+        self.games += 1;
+        self.rolls += rolls;
+        if game.has_won() {
+            self.wins += 1;
+        }
+        if game.has_fish() {
+            self.fish += 1;
+        }
+        let score = game.score().clamp(self.min_score, self.max_score);
+        self.score_histogram[(score - self.min_score) as usize] += 1;
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        self.rate(self.wins)
+    }
+
+    pub fn fish_rate(&self) -> f64 {
+        self.rate(self.fish)
+    }
+
+    pub fn average_rolls(&self) -> f64 {
+        self.rate(self.rolls)
+    }
+
+    pub fn average_score(&self) -> f64 {
+        let total: i64 = self
+            .score_histogram()
+            .map(|(score, count)| score as i64 * count as i64)
+            .sum();
+        total as f64 / self.games as f64
+    }
+
+    /// The number of games per final score, starting with the fish penalty or zero.
+    pub fn score_histogram(&self) -> impl Iterator<Item = (i8, u32)> + '_ {
+        (self.min_score..=self.max_score).zip(self.score_histogram.iter().copied())
+    }
+
+    pub fn games_with_score(&self, score: i8) -> u32 {
+        if (self.min_score..=self.max_score).contains(&score) {
+            self.score_histogram[(score - self.min_score) as usize]
+        } else {
+            0
+        }
+    }
+
+    fn rate(&self, count: u32) -> f64 {
+        if self.games == 0 {
+            0.0
+        } else {
+            count as f64 / self.games as f64
+        }
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "games: {}, win rate: {:.4}, fish rate: {:.4}, average score: {:.3}, average rolls: {:.3}",
+            self.games,
+            self.win_rate(),
+            self.fish_rate(),
+            self.average_score(),
+            self.average_rolls(),
+        )
+    }
+}
+
+/// Plays `number_of_games` games by the standard rules with the default strategy, seeding game
+/// `n` with `seed + n`.
+pub fn simulate(number_of_games: u32, seed: u64) -> Statistics {
+    simulate_with(number_of_games, seed, Rules::TWO_FOUR_EIGHTEEN, |_| {
+        DefaultStrategy
+    })
+}
+
+/// Plays `number_of_games` games by `rules` with the strategies created by `new_strategy`,
+/// seeding game `n` with `seed + n`. The seed of the game is passed to `new_strategy`.
+pub fn simulate_with<S, F>(
+    number_of_games: u32,
+    seed: u64,
+    rules: Rules,
+    mut new_strategy: F,
+) -> Statistics
+where
+    S: Strategy,
+    F: FnMut(u64) -> S,
+{
+    let mut statistics = Statistics::new(&rules);
+    for game_seed in (0..number_of_games as u64).map(|n| seed.wrapping_add(n)) {
+        let mut game = Game::with_strategy(
+            SmallRng::seed_from_u64(game_seed),
+            rules,
+            new_strategy(game_seed),
+        );
+        let mut rolls = 0;
+        while game.dice_left > NumberOfDice::Zero {
+            game.roll();
+            rolls += 1;
+        }
+        statistics.record(&game, rolls);
+    }
+    statistics
+}
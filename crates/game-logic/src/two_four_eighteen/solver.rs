@@ -0,0 +1,210 @@
+// This module only depends on `core` since the build script includes it to precompute the
+// value tables used on the device.
+
+pub const NUMBER_OF_DICE: usize = 5;
+pub const NUMBER_OF_FACES: usize = 6;
+pub const TABLE_LEN: usize = 2 * 2 * (NUMBER_OF_DICE + 1) * (MAX_TOTAL + 1);
+
+const MAX_TOTAL: usize = NUMBER_OF_FACES * NUMBER_OF_DICE;
+const TWO: usize = 1;
+const FOUR: usize = 3;
+const QUALIFIERS_TOTAL: i8 = 4 + 2;
+const FISH_SCORE: i8 = -1;
+const WINNING_SCORE: i8 = 18;
+
+/// The number of dice showing each face, `counts[0]` being the ones.
+pub type Counts = [u8; NUMBER_OF_FACES];
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Objective {
+    ExpectedScore,
+    WinProbability,
+}
+
+/// Everything about a game that matters for the rest of it: whether the qualifiers are
+/// picked, how many dice are left to roll and the sum of all picked dice.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Position {
+    pub has_four: bool,
+    pub has_two: bool,
+    pub dice_left: u8,
+    pub total: u8,
+}
+
+impl Position {
+    pub const START: Position = Position {
+        has_four: false,
+        has_two: false,
+        dice_left: NUMBER_OF_DICE as u8,
+        total: 0,
+    };
+
+    pub fn keep(&self, kept: &Counts) -> Position {
+        let number_of_kept: u8 = kept.iter().sum();
+        let kept_total: u8 = kept
+            .iter()
+            .enumerate()
+            .map(|(face, &count)| (face as u8 + 1) * count)
+            .sum();
+        Position {
+            has_four: self.has_four || kept[FOUR] > 0,
+            has_two: self.has_two || kept[TWO] > 0,
+            dice_left: self.dice_left - number_of_kept,
+            total: self.total + kept_total,
+        }
+    }
+
+    pub fn final_score(&self) -> i8 {
+        if self.has_four && self.has_two {
+            self.total as i8 - QUALIFIERS_TOTAL
+        } else {
+            FISH_SCORE
+        }
+    }
+
+    fn is_reachable(&self) -> bool {
+        let number_of_picked = NUMBER_OF_DICE - self.dice_left as usize;
+        let total = self.total as usize;
+        number_of_picked <= total && total <= NUMBER_OF_FACES * number_of_picked
+    }
+
+    fn index(&self) -> usize {
+        ((self.has_four as usize * 2 + self.has_two as usize) * (NUMBER_OF_DICE + 1)
+            + self.dice_left as usize)
+            * (MAX_TOTAL + 1)
+            + self.total as usize
+    }
+}
+
+/// The value of every position when playing optimally for an objective.
+pub struct ValueTable {
+    values: [f32; TABLE_LEN],
+}
+
+impl ValueTable {
+    pub const fn from_values(values: [f32; TABLE_LEN]) -> Self {
+        Self { values }
+    }
+
+    pub fn solve(objective: Objective) -> Self {
+        let mut table = Self::from_values([0.0; TABLE_LEN]);
+
+        for dice_left in 0..=NUMBER_OF_DICE as u8 {
+            for index in 0..4 {
+                for total in 0..=MAX_TOTAL as u8 {
+                    let position = Position {
+                        has_four: index / 2 == 1,
+                        has_two: index % 2 == 1,
+                        dice_left,
+                        total,
+                    };
+                    if position.is_reachable() {
+                        table.values[position.index()] = table.evaluate(objective, position);
+                    }
+                }
+            }
+        }
+        table
+    }
+
+    pub fn values(&self) -> &[f32; TABLE_LEN] {
+        &self.values
+    }
+
+    pub fn value(&self, position: Position) -> f32 {
+        self.values[position.index()]
+    }
+
+    /// Returns the dice to keep out of `rolled` that lead to the most valuable position.
+    pub fn best_keep(&self, position: Position, rolled: &Counts) -> Counts {
+        let mut best_keep = [0; NUMBER_OF_FACES];
+        let mut best_value = f32::NEG_INFINITY;
+        for_each_keep(rolled, |kept| {
+            let value = self.value(position.keep(kept));
+            if value > best_value {
+                best_value = value;
+                best_keep = *kept;
+            }
+        });
+        best_keep
+    }
+
+    fn evaluate(&self, objective: Objective, position: Position) -> f32 {
+        if position.dice_left == 0 {
+            let score = position.final_score();
+            return match objective {
+                Objective::ExpectedScore => score as f32,
+                Objective::WinProbability if score == WINNING_SCORE => 1.0,
+                Objective::WinProbability => 0.0,
+            };
+        }
+
+        let mut expected_value = 0.0;
+        for_each_roll(position.dice_left, |rolled, probability| {
+            let best_keep = self.best_keep(position, rolled);
+            expected_value += probability * self.value(position.keep(&best_keep)) as f64;
+        });
+        expected_value as f32
+    }
+}
+
+/// Calls `f` with every distinguishable outcome of rolling `number_of_dice` dice and its
+/// probability.
+pub fn for_each_roll<F>(number_of_dice: u8, mut f: F)
+where
+    F: FnMut(&Counts, f64),
+{
+    let mut counts = [0; NUMBER_OF_FACES];
+    let outcomes = (0..number_of_dice).fold(1.0, |acc, _| acc * NUMBER_OF_FACES as f64);
+    roll_faces(&mut counts, 0, number_of_dice, &mut |counts| {
+        f(counts, permutations(counts) / outcomes)
+    });
+}
+
+fn roll_faces<F>(counts: &mut Counts, face: usize, dice_left: u8, f: &mut F)
+where
+    F: FnMut(&Counts),
+{
+    if face == NUMBER_OF_FACES - 1 {
+        counts[face] = dice_left;
+        f(counts);
+        return;
+    }
+    for count in 0..=dice_left {
+        counts[face] = count;
+        roll_faces(counts, face + 1, dice_left - count, f);
+    }
+}
+
+fn permutations(counts: &Counts) -> f64 {
+    let number_of_dice: u8 = counts.iter().sum();
+    counts
+        .iter()
+        .fold(factorial(number_of_dice), |acc, &count| {
+            acc / factorial(count)
+        })
+}
+
+fn factorial(number: u8) -> f64 {
+    (1..=number).fold(1.0, |acc, factor| acc * factor as f64)
+}
+
+/// Calls `f` with every non-empty selection of dice out of `rolled`.
+fn for_each_keep<F>(rolled: &Counts, mut f: F)
+where
+    F: FnMut(&Counts),
+{
+    let mut kept = [0; NUMBER_OF_FACES];
+    loop {
+        let mut face = 0;
+        while face < NUMBER_OF_FACES && kept[face] == rolled[face] {
+            kept[face] = 0;
+            face += 1;
+        }
+        if face == NUMBER_OF_FACES {
+            return;
+        }
+        kept[face] += 1;
+        f(&kept);
+    }
+}
@@ -0,0 +1,154 @@
+use pico_display::dice::Dice;
+use pico_display::die::FaceValue;
+use rand::Rng;
+use rand::rngs::SmallRng;
+
+use super::rules::Rules;
+use super::{NumberOfDice, count, has_six};
+
+/// Decides which of the rolled dice to keep.
+///
+/// `rules` are the rules of the game, `rolled` holds the dice of the current roll, `picked` the
+/// dice kept in earlier rolls and `dice_left` the number of dice that were rolled. The returned
+/// dice must be taken from `rolled`, anything else is ignored by the game. If nothing is kept,
+/// the game keeps the highest rolled die since at least one die needs to be picked per roll.
+pub trait Strategy {
+    fn pick(
+        &mut self,
+        rules: &Rules,
+        rolled: &Dice,
+        picked: &Dice,
+        dice_left: NumberOfDice,
+    ) -> Dice;
+}
+
+/// Secures the qualifiers first and then keeps the dice that beat the expected value of
+/// re-rolling them.
+#[derive(Default, Clone, Copy)]
+pub struct DefaultStrategy;
+
+impl Strategy for DefaultStrategy {
+    fn pick(
+        &mut self,
+        rules: &Rules,
+        rolled: &Dice,
+        picked: &Dice,
+        dice_left: NumberOfDice,
+    ) -> Dice {
+        let mut rolled = rolled.clone();
+        let mut picked = picked.clone();
+        let previously_picked = picked.len();
+
+        pick_qualifiers(rules, &mut rolled, &mut picked);
+        if !rules.has_fish(&picked) {
+            let pick_gte = pick_gte_when_no_fish(rules, &rolled, &picked, dice_left);
+            picked.append(&mut rolled.pick(|value| value >= pick_gte, None));
+        }
+
+        Dice::from(picked.dice.split_off(previously_picked))
+    }
+}
+
+fn pick_gte_when_no_fish(
+    rules: &Rules,
+    rolled: &Dice,
+    picked: &Dice,
+    dice_left_before_roll: NumberOfDice,
+) -> FaceValue {
+    if rules.can_win(picked) && (has_six(rolled) || dice_left_before_roll > rules.dice_left(picked))
+    {
+        FaceValue::Six
+    } else {
+        let dice_left = rules.dice_left(picked);
+        if dice_left <= NumberOfDice::Two
+            || count(rolled, |value| value >= FaceValue::Five) >= 2
+            || count(rolled, |value| value >= FaceValue::Four) >= 3
+        {
+            // The expection value for rolling a die is 3.5.
+            FaceValue::Four
+        } else {
+            // The expection value for rolling a die with the option to re-roll it is 4.25.
+            // 4.25 = (4 + 5 + 6) / 3 * 1/2 + 3.5 * 1/2
+            FaceValue::Five
+        }
+    }
+}
+
+/// Secures the qualifiers and keeps every die above the expected value of a roll.
+#[derive(Default, Clone, Copy)]
+pub struct GreedyStrategy;
+
+impl Strategy for GreedyStrategy {
+    fn pick(
+        &mut self,
+        rules: &Rules,
+        rolled: &Dice,
+        picked: &Dice,
+        _dice_left: NumberOfDice,
+    ) -> Dice {
+        let mut rolled = rolled.clone();
+        let mut picked = picked.clone();
+        let previously_picked = picked.len();
+
+        pick_qualifiers(rules, &mut rolled, &mut picked);
+        if !rules.has_fish(&picked) {
+            picked.append(&mut rolled.pick(|value| value >= FaceValue::Four, None));
+        }
+
+        Dice::from(picked.dice.split_off(previously_picked))
+    }
+}
+
+/// Keeps a single die per roll: a missing qualifier if there is one, otherwise the highest die.
+#[derive(Default, Clone, Copy)]
+pub struct AlwaysMaxStrategy;
+
+impl Strategy for AlwaysMaxStrategy {
+    fn pick(
+        &mut self,
+        rules: &Rules,
+        rolled: &Dice,
+        picked: &Dice,
+        _dice_left: NumberOfDice,
+    ) -> Dice {
+        let mut rolled = rolled.clone();
+
+        let mut kept = rules.pick_qualifiers(picked, &mut rolled);
+        kept.dice.truncate(1);
+        if kept.is_empty()
+            && let Some(die) = rolled.max()
+        {
+            kept.push(die);
+        }
+        kept
+    }
+}
+
+/// Keeps every rolled die with a probability of one half.
+pub struct RandomStrategy {
+    small_rng: SmallRng,
+}
+
+impl RandomStrategy {
+    pub fn new(small_rng: SmallRng) -> Self {
+        Self { small_rng }
+    }
+}
+
+impl Strategy for RandomStrategy {
+    fn pick(
+        &mut self,
+        _rules: &Rules,
+        rolled: &Dice,
+        _picked: &Dice,
+        _dice_left: NumberOfDice,
+    ) -> Dice {
+        let mut rolled = rolled.clone();
+        rolled.pick(|_| self.small_rng.random_bool(0.5), None)
+    }
+}
+
+fn pick_qualifiers(rules: &Rules, rolled: &mut Dice, picked: &mut Dice) {
+    let mut kept = rules.pick_qualifiers(picked, rolled);
+    picked.append(&mut kept);
+}
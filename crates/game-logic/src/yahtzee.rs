@@ -0,0 +1,205 @@
+pub mod scorecard;
+pub mod strategy;
+
+use core::fmt;
+use pico_display::dice::Dice;
+use pico_display::die::Die;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use crate::dice_game::{DiceGame, Outcome};
+use scorecard::{Category, NUMBER_OF_CATEGORIES, Scorecard};
+use strategy::{DefaultStrategy, Strategy};
+
+pub const NUMBER_OF_DICE: usize = 5;
+pub const ROLLS_PER_TURN: u8 = 3;
+pub const NUMBER_OF_TURNS: usize = NUMBER_OF_CATEGORIES;
+
+/// The dice held between rolls, by position.
+pub type Holds = [bool; NUMBER_OF_DICE];
+
+/// A single roll, along with where it was scored if it ended the turn.
+#[derive(Clone)]
+pub struct Event {
+    pub rolled: Dice,
+    pub held: Holds,
+    pub scored: Option<(Category, u8)>,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("rolled: ")?;
+        self.rolled
+            .dice
+            .iter()
+            .try_for_each(|die| write!(f, "{}", die.value.as_u8()))?;
+        f.write_str(", held: ")?;
+        self.held
+            .iter()
+            .try_for_each(|&held| f.write_str(if held { "x" } else { "_" }))?;
+        match self.scored {
+            Some((category, score)) => write!(f, ", scored: {} {score}", category.label()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Solitaire Yahtzee: thirteen turns of up to three rolls, each scored in a category of its
+/// own.
+pub struct Game<S = DefaultStrategy> {
+    pub small_rng: SmallRng,
+    pub strategy: S,
+    pub rolled: Dice,
+    /// The dice held before the last roll.
+    pub held: Holds,
+    pub rolls_left: u8,
+    pub scorecard: Scorecard,
+    last_event: Option<Event>,
+}
+
+impl Game {
+    pub fn new(small_rng: SmallRng) -> Self {
+        Self::with_strategy(small_rng, DefaultStrategy)
+    }
+}
+
+impl<S> Game<S>
+where
+    S: Strategy,
+{
+    pub fn with_strategy(small_rng: SmallRng, strategy: S) -> Self {
+        Self {
+            small_rng,
+            strategy,
+            rolled: Dice::empty(),
+            held: [false; NUMBER_OF_DICE],
+            rolls_left: ROLLS_PER_TURN,
+            scorecard: Scorecard::default(),
+            last_event: None,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.rolled = Dice::empty();
+        self.held = [false; NUMBER_OF_DICE];
+        self.rolls_left = ROLLS_PER_TURN;
+        self.scorecard = Scorecard::default();
+        self.last_event = None;
+    }
+
+    /// Rolls every die on the first roll of a turn and the dice not held by the strategy
+    /// afterwards. The turn is scored after the last roll or as soon as every die is held.
+    pub fn roll(&mut self) {
+        if self.is_over() {
+            return;
+        }
+        self.held = if self.rolls_left == ROLLS_PER_TURN {
+            [false; NUMBER_OF_DICE]
+        } else {
+            self.strategy
+                .hold(&self.rolled, self.rolls_left, &self.scorecard)
+        };
+        if self.held.iter().all(|&held| held) {
+            let scored = self.score();
+            self.record(Some(scored));
+            return;
+        }
+
+        let mut rolled = Dice::empty();
+        for index in 0..NUMBER_OF_DICE {
+            match self.rolled.dice.get(index) {
+                Some(&die) if self.held[index] => rolled.push(die),
+                _ => rolled.push(Die::new(self.small_rng.random())),
+            }
+        }
+        self.rolled = rolled;
+        self.rolls_left -= 1;
+
+        let scored = (self.rolls_left == 0).then(|| self.score());
+        self.record(scored);
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.scorecard.is_complete()
+    }
+
+    pub fn total(&self) -> u16 {
+        self.scorecard.total()
+    }
+
+    /// Scores the rolled dice in the category chosen by the strategy, or in the allowed
+    /// category that scores the most if the strategy's choice is not allowed.
+    fn score(&mut self) -> (Category, u8) {
+        let chosen = self.strategy.choose(&self.rolled, &self.scorecard);
+        let category = if self.scorecard.allows(chosen, &self.rolled) {
+            chosen
+        } else {
+            self.scorecard
+                .open_categories()
+                .filter(|&category| self.scorecard.allows(category, &self.rolled))
+                .max_by_key(|&category| self.scorecard.score_for(category, &self.rolled))
+                .unwrap_or(chosen)
+        };
+        let score = self
+            .scorecard
+            .fill(category, &self.rolled)
+            .unwrap_or_default();
+        self.rolls_left = ROLLS_PER_TURN;
+        (category, score)
+    }
+
+    fn record(&mut self, scored: Option<(Category, u8)>) {
+        self.last_event = Some(Event {
+            rolled: self.rolled.clone(),
+            held: self.held,
+            scored,
+        });
+    }
+}
+
+impl<S> DiceGame for Game<S>
+where
+    S: Strategy,
+{
+    type Event = Event;
+    type Log = Scorecard;
+
+    const LOST_MESSAGE: &'static str = "Game over";
+
+    fn roll(&mut self) {
+        Game::roll(self);
+    }
+
+    fn rolled(&self) -> &Dice {
+        &self.rolled
+    }
+
+    fn kept(&self) -> &Dice {
+        &self.rolled
+    }
+
+    fn outcome(&self) -> Outcome {
+        if self.is_over() {
+            Outcome::Over(i16::try_from(self.total()).unwrap_or(i16::MAX))
+        } else {
+            Outcome::Playing
+        }
+    }
+
+    fn reset(&mut self) {
+        Game::reset(self);
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.small_rng = SmallRng::seed_from_u64(seed);
+        Game::reset(self);
+    }
+
+    fn last_event(&self) -> Option<&Event> {
+        self.last_event.as_ref()
+    }
+
+    fn log(&self) -> &Scorecard {
+        &self.scorecard
+    }
+}
@@ -0,0 +1,289 @@
+use core::fmt;
+use pico_display::dice::Dice;
+use pico_display::scorecard::Entry;
+
+pub const NUMBER_OF_CATEGORIES: usize = 13;
+pub const UPPER_BONUS_THRESHOLD: u16 = 63;
+pub const UPPER_BONUS: u16 = 35;
+pub const YAHTZEE_BONUS: u16 = 100;
+pub const FULL_HOUSE: u8 = 25;
+pub const SMALL_STRAIGHT: u8 = 30;
+pub const LARGE_STRAIGHT: u8 = 40;
+pub const YAHTZEE: u8 = 50;
+/// The categories followed by the upper bonus, the Yahtzee bonus and the total.
+pub const NUMBER_OF_ENTRIES: usize = NUMBER_OF_CATEGORIES + 3;
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Category {
+    Ones,
+    Twos,
+    Threes,
+    Fours,
+    Fives,
+    Sixes,
+    ThreeOfAKind,
+    FourOfAKind,
+    FullHouse,
+    SmallStraight,
+    LargeStraight,
+    Yahtzee,
+    Chance,
+}
+
+impl Category {
+    pub const ALL: [Category; NUMBER_OF_CATEGORIES] = [
+        Category::Ones,
+        Category::Twos,
+        Category::Threes,
+        Category::Fours,
+        Category::Fives,
+        Category::Sixes,
+        Category::ThreeOfAKind,
+        Category::FourOfAKind,
+        Category::FullHouse,
+        Category::SmallStraight,
+        Category::LargeStraight,
+        Category::Yahtzee,
+        Category::Chance,
+    ];
+
+    /// The upper section category counting `face`.
+    pub fn upper(face: u8) -> Option<Category> {
+        Category::ALL[..6]
+            .get((face as usize).wrapping_sub(1))
+            .copied()
+    }
+
+    /// The face counted by an upper section category.
+    pub fn face(&self) -> Option<u8> {
+        self.is_upper().then_some(self.index() as u8 + 1)
+    }
+
+    pub fn is_upper(&self) -> bool {
+        self.index() < 6
+    }
+
+    /// A short name that fits the scorecard.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Category::Ones => "Ones",
+            Category::Twos => "Twos",
+            Category::Threes => "Threes",
+            Category::Fours => "Fours",
+            Category::Fives => "Fives",
+            Category::Sixes => "Sixes",
+            Category::ThreeOfAKind => "3 kind",
+            Category::FourOfAKind => "4 kind",
+            Category::FullHouse => "F house",
+            Category::SmallStraight => "S strt",
+            Category::LargeStraight => "L strt",
+            Category::Yahtzee => "Yahtzee",
+            Category::Chance => "Chance",
+        }
+    }
+
+    /// The score of the dice in this category without the joker rule.
+    pub fn score(&self, dice: &Dice) -> u8 {
+        let counts = dice.face_counts();
+        let total = dice.dice.iter().map(|die| die.value.as_u8()).sum();
+        let max_count = counts.iter().copied().max().unwrap_or_default();
+        match self {
+            Category::ThreeOfAKind if max_count >= 3 => total,
+            Category::FourOfAKind if max_count >= 4 => total,
+            Category::FullHouse if counts.contains(&3) && counts.contains(&2) => FULL_HOUSE,
+            Category::SmallStraight if longest_run(&counts) >= 4 => SMALL_STRAIGHT,
+            Category::LargeStraight if longest_run(&counts) >= 5 => LARGE_STRAIGHT,
+            Category::Yahtzee if max_count >= 5 => YAHTZEE,
+            Category::Chance => total,
+            category => match category.face() {
+                Some(face) => counts[face as usize - 1] * face,
+                None => 0,
+            },
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ScorecardError {
+    Taken,
+    /// A Yahtzee rolled as a joker has to go into its upper category if that is open, and
+    /// into the lower section otherwise.
+    JokerRule,
+}
+
+impl fmt::Display for ScorecardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ScorecardError::Taken => "Category\nis taken.",
+            ScorecardError::JokerRule => "Joker rule\napplies.",
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct Scorecard {
+    scores: [Option<u8>; NUMBER_OF_CATEGORIES],
+    yahtzee_bonuses: u8,
+}
+
+impl Scorecard {
+    pub fn score(&self, category: Category) -> Option<u8> {
+        self.scores[category.index()]
+    }
+
+    pub fn is_open(&self, category: Category) -> bool {
+        self.score(category).is_none()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.scores.iter().all(Option::is_some)
+    }
+
+    pub fn open_categories(&self) -> impl Iterator<Item = Category> + '_ {
+        Category::ALL
+            .into_iter()
+            .filter(|&category| self.is_open(category))
+    }
+
+    /// Whether the dice are a Yahtzee that has to be played as a joker, i.e. the Yahtzee
+    /// category is already filled.
+    pub fn is_joker(&self, dice: &Dice) -> bool {
+        Category::Yahtzee.score(dice) == YAHTZEE && !self.is_open(Category::Yahtzee)
+    }
+
+    /// Whether the dice may be scored in the category.
+    pub fn allows(&self, category: Category, dice: &Dice) -> bool {
+        if !self.is_open(category) {
+            return false;
+        }
+        if !self.is_joker(dice) {
+            return true;
+        }
+        let upper = dice
+            .dice
+            .first()
+            .and_then(|die| Category::upper(die.value.as_u8()));
+        match upper {
+            Some(upper) if self.is_open(upper) => category == upper,
+            _ if self.open_categories().any(|category| !category.is_upper()) => {
+                !category.is_upper()
+            }
+            _ => true,
+        }
+    }
+
+    /// The score of the dice in the category, counting a joker as full house and straights.
+    pub fn score_for(&self, category: Category, dice: &Dice) -> u8 {
+        if !self.is_joker(dice) {
+            return category.score(dice);
+        }
+        match category {
+            Category::FullHouse => FULL_HOUSE,
+            Category::SmallStraight => SMALL_STRAIGHT,
+            Category::LargeStraight => LARGE_STRAIGHT,
+            category => category.score(dice),
+        }
+    }
+
+    /// Scores the dice in the category and returns the score, not counting a Yahtzee bonus.
+    pub fn fill(&mut self, category: Category, dice: &Dice) -> Result<u8, ScorecardError> {
+        if !self.is_open(category) {
+            return Err(ScorecardError::Taken);
+        }
+        if !self.allows(category, dice) {
+            return Err(ScorecardError::JokerRule);
+        }
+        if self.is_joker(dice) && self.score(Category::Yahtzee) == Some(YAHTZEE) {
+            self.yahtzee_bonuses += 1;
+        }
+        let score = self.score_for(category, dice);
+        self.scores[category.index()] = Some(score);
+        Ok(score)
+    }
+
+    pub fn upper_total(&self) -> u16 {
+        self.section_total(true)
+    }
+
+    pub fn upper_bonus(&self) -> u16 {
+        if self.upper_total() >= UPPER_BONUS_THRESHOLD {
+            UPPER_BONUS
+        } else {
+            0
+        }
+    }
+
+    pub fn lower_total(&self) -> u16 {
+        self.section_total(false)
+    }
+
+    pub fn yahtzee_bonus(&self) -> u16 {
+        self.yahtzee_bonuses as u16 * YAHTZEE_BONUS
+    }
+
+    pub fn total(&self) -> u16 {
+        self.upper_total() + self.upper_bonus() + self.lower_total() + self.yahtzee_bonus()
+    }
+
+    /// The categories, the bonuses and the total as they are drawn by
+    /// `pico_display::scorecard::draw_scorecard`. The upper bonus is left blank until it is
+    /// decided.
+    pub fn entries(&self) -> [Entry<'static>; NUMBER_OF_ENTRIES] {
+        let mut entries = [Entry::new("", None); NUMBER_OF_ENTRIES];
+        let upper_done = Category::ALL[..6]
+            .iter()
+            .all(|&category| !self.is_open(category));
+        let upper_bonus = (upper_done || self.upper_bonus() > 0).then_some(self.upper_bonus());
+
+        let categories = Category::ALL
+            .iter()
+            .map(|&category| Entry::new(category.label(), self.score(category).map(u16::from)));
+        let (upper, lower) = entries.split_at_mut(6);
+        for (entry, category) in upper.iter_mut().zip(categories.clone().take(6)) {
+            *entry = category;
+        }
+        lower[0] = Entry::new("Bonus", upper_bonus);
+        for (entry, category) in lower[1..].iter_mut().zip(categories.skip(6)) {
+            *entry = category;
+        }
+        entries[NUMBER_OF_ENTRIES - 2] = Entry::new("Yz bonus", Some(self.yahtzee_bonus()));
+        entries[NUMBER_OF_ENTRIES - 1] = Entry::new("Total", Some(self.total()));
+        entries
+    }
+
+    fn section_total(&self, upper: bool) -> u16 {
+        Category::ALL
+            .iter()
+            .filter(|category| category.is_upper() == upper)
+            .filter_map(|&category| self.score(category))
+            .map(u16::from)
+            .sum()
+    }
+}
+
+impl fmt::Display for Scorecard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in self.entries() {
+            match entry.score {
+                Some(score) => write!(f, "{}: {score}, ", entry.label)?,
+                None => write!(f, "{}: -, ", entry.label)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The length of the longest run of consecutive faces.
+pub fn longest_run(counts: &[u8; 6]) -> u8 {
+    let mut longest = 0;
+    let mut run = 0;
+    for &count in counts {
+        run = if count > 0 { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    longest
+}
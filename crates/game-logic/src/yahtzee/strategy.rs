@@ -0,0 +1,112 @@
+use pico_display::dice::Dice;
+
+use super::Holds;
+use super::scorecard::{Category, Scorecard, longest_run};
+
+/// Decides which dice to hold between rolls and where to score them.
+pub trait Strategy {
+    /// Returns the dice to hold before rolling again, by position in `rolled`. Holding every
+    /// die scores the roll right away.
+    fn hold(&mut self, rolled: &Dice, rolls_left: u8, scorecard: &Scorecard) -> Holds;
+
+    /// Returns the category to score the dice in. A category the scorecard does not allow is
+    /// ignored and the dice are scored in the allowed category that scores the most.
+    fn choose(&mut self, rolled: &Dice, scorecard: &Scorecard) -> Category;
+}
+
+/// Stands on a made straight, full house or Yahtzee, goes for a straight on a run of three
+/// and otherwise holds the most frequent face. Scores in the category that beats its par the
+/// most, so that zeros end up where they hurt the least.
+#[derive(Default, Clone, Copy)]
+pub struct DefaultStrategy;
+
+impl Strategy for DefaultStrategy {
+    fn hold(&mut self, rolled: &Dice, _rolls_left: u8, scorecard: &Scorecard) -> Holds {
+        let is_made = |category| {
+            scorecard.allows(category, rolled) && scorecard.score_for(category, rolled) > 0
+        };
+        let small_straight_is_best =
+            is_made(Category::SmallStraight) && !scorecard.is_open(Category::LargeStraight);
+        if is_made(Category::Yahtzee)
+            || is_made(Category::LargeStraight)
+            || is_made(Category::FullHouse)
+            || small_straight_is_best
+        {
+            return [true; super::NUMBER_OF_DICE];
+        }
+
+        let counts = rolled.face_counts();
+        let (most_frequent, count) = (1..=6u8)
+            .map(|face| (face, counts[face as usize - 1]))
+            .max_by_key(|&(face, count)| (count, face))
+            .unwrap_or_default();
+        let goes_for_straight = (scorecard.is_open(Category::SmallStraight)
+            || scorecard.is_open(Category::LargeStraight))
+            && count < 3
+            && longest_run(&counts) >= 3;
+
+        let mut holds = [false; super::NUMBER_OF_DICE];
+        if goes_for_straight {
+            let run = run_faces(&counts);
+            let mut held_faces = [false; 6];
+            for (hold, die) in holds.iter_mut().zip(rolled.dice.iter()) {
+                let face = die.value.as_u8();
+                if run.contains(&face) && !held_faces[face as usize - 1] {
+                    held_faces[face as usize - 1] = true;
+                    *hold = true;
+                }
+            }
+        } else {
+            for (hold, die) in holds.iter_mut().zip(rolled.dice.iter()) {
+                *hold = die.value.as_u8() == most_frequent;
+            }
+        }
+        holds
+    }
+
+    fn choose(&mut self, rolled: &Dice, scorecard: &Scorecard) -> Category {
+        Category::ALL
+            .into_iter()
+            .rev()
+            .filter(|&category| scorecard.allows(category, rolled))
+            .max_by_key(|&category| {
+                let over_par = scorecard.score_for(category, rolled) as i16 - par(category) as i16;
+                // points over par in the upper section count twice as they go towards the bonus
+                if category.is_upper() {
+                    over_par + over_par.max(0)
+                } else {
+                    over_par
+                }
+            })
+            .unwrap_or(Category::Chance)
+    }
+}
+
+/// A score that is fair to expect in the category over a whole game.
+fn par(category: Category) -> u8 {
+    match category {
+        Category::ThreeOfAKind => 15,
+        Category::FourOfAKind => 15,
+        Category::FullHouse => 20,
+        Category::SmallStraight => 25,
+        Category::LargeStraight => 30,
+        Category::Yahtzee => 25,
+        Category::Chance => 22,
+        // three of each face reach the upper bonus
+        upper => upper.face().unwrap_or_default() * 3,
+    }
+}
+
+/// The faces of the longest run, the highest one if there are two.
+fn run_faces(counts: &[u8; 6]) -> core::ops::RangeInclusive<u8> {
+    let longest = longest_run(counts);
+    let mut run = 0;
+    let mut last = 0;
+    for (face, &count) in (1..=6u8).zip(counts) {
+        run = if count > 0 { run + 1 } else { 0 };
+        if run == longest {
+            last = face;
+        }
+    }
+    (last + 1).saturating_sub(longest)..=last
+}
@@ -0,0 +1,159 @@
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+use rand::Rng;
+
+extern crate alloc;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::aliases::Display;
+use crate::dice::{Dice, RenderStyle};
+use crate::die::{Die, DieFace, DieStyle, RandomFace};
+use crate::utils;
+
+/// The animation is played at a steady 20 frames per second.
+pub const FRAME_DURATION_IN_MS: u64 = 50;
+pub const MIN_FRAMES: u32 = 6;
+pub const MAX_FRAMES: u32 = 40;
+/// Every this long the beam was broken adds a frame to the animation.
+const BEAM_DURATION_PER_FRAME_IN_MUS: u64 = 50_000;
+/// How many frames the faces stay the same right before the dice land. The dice change faces
+/// every frame at first and slow down towards this.
+const MAX_FRAMES_PER_FACE: u32 = 5;
+/// How far a tumbling die moves within its cell, which is also how much it is shrunk by on
+/// each side to stay inside.
+const JITTER_IN_PERCENT: u32 = 10;
+/// The jitter is kept in thousandths of the margin, so that it scales with the cell.
+const JITTER_SCALE: i32 = 1000;
+
+/// The number of frames of a roll after breaking the beam for the given duration, the longer
+/// the dice are shaken the longer they tumble.
+pub fn frames_for_beam_duration(beam_duration_in_mus: u64) -> u32 {
+    let frames = MIN_FRAMES as u64 + beam_duration_in_mus / BEAM_DURATION_PER_FRAME_IN_MUS;
+    frames.min(MAX_FRAMES as u64) as u32
+}
+
+/// Dice tumbling before they land: random faces at jittered positions, changing less and less
+/// often until the last frame shows the dice as they were rolled.
+pub struct RollAnimation<D = Die> {
+    rolled: Dice<D>,
+    shown: Dice<D>,
+    jitter: Vec<(i32, i32)>,
+    frame: u32,
+    frames: u32,
+    theme: DieStyle,
+}
+
+impl<D> RollAnimation<D>
+where
+    D: DieFace + RandomFace + Clone,
+{
+    /// Starts tumbling the rolled dice for `frames` frames before they land.
+    pub fn new<R>(rolled: &Dice<D>, frames: u32, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let mut animation = Self {
+            rolled: rolled.clone(),
+            shown: rolled.clone(),
+            jitter: vec![(0, 0); rolled.len()],
+            frame: 0,
+            frames,
+            theme: DieStyle::CLASSIC,
+        };
+        if !animation.is_landed() {
+            animation.tumble(rng);
+        }
+        animation
+    }
+
+    /// Draws the dice in `theme` instead of the classic one.
+    pub fn themed(self, theme: DieStyle) -> Self {
+        Self { theme, ..self }
+    }
+
+    /// Moves on to the next frame, returning false once the dice have landed and there is
+    /// nothing left to draw.
+    pub fn advance<R>(&mut self, rng: &mut R) -> bool
+    where
+        R: Rng + ?Sized,
+    {
+        if self.is_landed() {
+            return false;
+        }
+        self.frame += 1;
+        if self.is_landed() {
+            self.shown = self.rolled.clone();
+            self.jitter.fill((0, 0));
+        } else if self.frame.is_multiple_of(self.frames_per_face()) {
+            self.tumble(rng);
+        }
+        true
+    }
+
+    pub fn draw<T>(&self, target: &mut T) -> Result<(), T::Error>
+    where
+        T: Display,
+    {
+        if self.is_landed() {
+            let style = RenderStyle::PLAIN.themed(self.theme);
+            return self.shown.draw_styled(target, |_| style);
+        }
+        self.shown.draw_each(target, |index, die, cell| {
+            let size = cell.size();
+            let side_length = size.width.min(size.height);
+            let margin = utils::percent_of(side_length, JITTER_IN_PERCENT);
+            let (x, y) = self.jitter[index];
+            let offset = |jitter: i32| margin as i32 + margin as i32 * jitter / JITTER_SCALE;
+            let area = Rectangle::new(
+                Point::new(offset(x), offset(y)),
+                Size::new_equal(side_length - 2 * margin),
+            );
+            die.draw_themed(&mut cell.cropped(&area), &self.theme, false)
+        })
+    }
+
+    /// The dice as they are shown in the current frame.
+    pub fn shown(&self) -> &Dice<D> {
+        &self.shown
+    }
+
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    pub fn is_landed(&self) -> bool {
+        self.frame >= self.frames
+    }
+
+    /// Grows with the square of the progress, so that the dice slow down towards the end.
+    fn frames_per_face(&self) -> u32 {
+        let progress = self.frame * self.frame;
+        1 + (MAX_FRAMES_PER_FACE - 1) * progress / (self.frames * self.frames)
+    }
+
+    /// Shows random faces, moved less the closer the dice are to landing.
+    fn tumble<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        let amplitude = JITTER_SCALE * (self.frames - self.frame) as i32 / self.frames as i32;
+        for ((shown, rolled), jitter) in self
+            .shown
+            .dice
+            .iter_mut()
+            .zip(&self.rolled.dice)
+            .zip(&mut self.jitter)
+        {
+            *shown = rolled.random_face(rng);
+            *jitter = (
+                rng.random_range(-amplitude..=amplitude),
+                rng.random_range(-amplitude..=amplitude),
+            );
+        }
+    }
+}
@@ -1,66 +1,170 @@
 use core::cmp::min;
+use embedded_graphics::draw_target::Cropped;
 use embedded_graphics::geometry::Size;
-use embedded_graphics::{prelude::*, primitives::rectangle::Rectangle};
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::primitives::{Line, PrimitiveStyle};
+use embedded_graphics::{prelude::*, primitives::rectangle::Rectangle, Pixel};
 
 extern crate alloc;
 use alloc::vec::Vec;
 
 use crate::aliases::Display;
-use crate::die::{Die, FaceValue};
+use crate::die::{Die, DieFace, DieStyle, FaceValue};
+use crate::utils;
 
-pub struct Dice {
-    pub dice: Vec<Die>,
+/// How much an underlined die is shrunk by on each side to make room for the line.
+const UNDERLINE_GAP_IN_PERCENT: u32 = 5;
+const MIN_UNDERLINE_GAP: u32 = 2;
+
+/// How a single die is drawn by `Dice::draw_styled`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Default)]
+pub struct RenderStyle {
+    /// Draws a filled face with blank pips.
+    pub inverted: bool,
+    /// Draws a line under the die, which is shrunk to make room for it.
+    pub underline: bool,
+    /// Leaves every other pixel of the die off, so that its outline looks dotted.
+    pub dimmed: bool,
+    /// The theme of the faces, picked on the settings screen.
+    pub theme: DieStyle,
 }
 
-impl Dice {
+impl RenderStyle {
+    pub const PLAIN: Self = Self {
+        inverted: false,
+        underline: false,
+        dimmed: false,
+        theme: DieStyle::CLASSIC,
+    };
+
+    pub const fn inverted(self) -> Self {
+        Self {
+            inverted: true,
+            ..self
+        }
+    }
+
+    pub const fn underlined(self) -> Self {
+        Self {
+            underline: true,
+            ..self
+        }
+    }
+
+    pub const fn dimmed(self) -> Self {
+        Self {
+            dimmed: true,
+            ..self
+        }
+    }
+
+    pub const fn themed(self, theme: DieStyle) -> Self {
+        Self { theme, ..self }
+    }
+}
+
+/// A hand of dice, six-sided ones unless another `DieFace` is given, e.g.
+/// `Dice<PolyhedralDie>`.
+#[derive(Clone, PartialEq)]
+pub struct Dice<D = Die> {
+    pub dice: Vec<D>,
+}
+
+impl<D> Dice<D> {
     pub fn empty() -> Self {
         Self { dice: Vec::new() }
     }
 
-    fn from(dice: Vec<Die>) -> Self {
+    fn from(dice: Vec<D>) -> Self {
         Self { dice }
     }
 
-    pub fn roll<F>(mut face_value: F, number_of_dice: u32) -> Self
+    pub fn append(&mut self, dice: &mut Dice<D>) {
+        self.dice.append(&mut dice.dice);
+    }
+
+    pub fn len(&self) -> usize {
+        self.dice.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dice.is_empty()
+    }
+
+    pub fn push(&mut self, die: D) {
+        self.dice.push(die);
+    }
+}
+
+impl<D> Dice<D>
+where
+    D: DieFace,
+{
+    pub fn draw<T>(&self, target: &mut T) -> Result<(), T::Error>
     where
-        F: FnMut() -> FaceValue,
+        T: Display,
     {
-        let mut dice = Vec::new();
-        for _ in 0..number_of_dice {
-            let die = Die::new(face_value());
-            dice.push(die);
-        }
-        Dice { dice }
+        self.draw_each(target, |_, die, target| die.draw(target))
     }
 
-    pub fn pick<F>(&mut self, decide: F, max_hits: Option<usize>) -> Self
+    /// Draws every die in the style `style_of` returns for its index.
+    pub fn draw_styled<T, F>(&self, target: &mut T, style_of: F) -> Result<(), T::Error>
     where
-        F: Fn(FaceValue) -> bool,
+        T: Display,
+        F: Fn(usize) -> RenderStyle,
     {
-        let max_hits = max_hits.unwrap_or(self.dice.len());
+        self.draw_each(target, |index, die, cell| {
+            draw_styled_die(die, cell, style_of(index))
+        })
+    }
 
-        let mut pick_counter = 0;
-        let mut keep = Vec::new();
-        let mut picked = Vec::new();
-        for die in self.dice.iter() {
-            if decide(die.value) && pick_counter < max_hits {
-                picked.push(*die);
-                pick_counter += 1;
-            } else {
-                keep.push(*die);
-            }
-        }
-        self.dice = keep;
-        picked.into()
+    /// Draws the dice with a frame around the die at `cursor` and the dice for which
+    /// `is_selected` returns true inverted.
+    pub fn draw_selection<T, F>(
+        &self,
+        target: &mut T,
+        cursor: usize,
+        is_selected: F,
+    ) -> Result<(), T::Error>
+    where
+        T: Display,
+        F: Fn(usize) -> bool,
+    {
+        self.draw_selection_themed(target, cursor, is_selected, DieStyle::CLASSIC)
     }
 
-    pub fn max(&self) -> Option<Die> {
-        self.dice.iter().max().copied()
+    /// Draws the selection like `draw_selection`, with the faces in `theme`.
+    pub fn draw_selection_themed<T, F>(
+        &self,
+        target: &mut T,
+        cursor: usize,
+        is_selected: F,
+        theme: DieStyle,
+    ) -> Result<(), T::Error>
+    where
+        T: Display,
+        F: Fn(usize) -> bool,
+    {
+        self.draw_each(target, |index, die, target| {
+            let style = RenderStyle {
+                inverted: is_selected(index),
+                ..RenderStyle::PLAIN.themed(theme)
+            };
+            draw_styled_die(die, target, style)?;
+            if index == cursor {
+                target
+                    .bounding_box()
+                    .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+                    .draw(target)?;
+            }
+            Ok(())
+        })
     }
 
-    pub fn draw<T>(&self, target: &mut T) -> Result<(), T::Error>
+    pub(crate) fn draw_each<T, F>(&self, target: &mut T, mut draw_die: F) -> Result<(), T::Error>
     where
         T: Display,
+        F: FnMut(usize, &D, &mut Cropped<'_, T>) -> Result<(), T::Error>,
     {
         let number_of_dice = self.dice.len() as u32;
         let size = target.size();
@@ -84,26 +188,127 @@ impl Dice {
 
             let area = Rectangle::new(Point::new(x as i32, y as i32), size);
 
-            let mut die = self.dice[counter];
-            die.draw(&mut target.cropped(&area))?;
+            draw_die(counter, &self.dice[counter], &mut target.cropped(&area))?;
         }
         Ok(())
     }
+}
 
-    pub fn append(&mut self, dice: &mut Dice) {
-        self.dice.append(&mut dice.dice);
+impl Dice {
+    pub fn roll<F>(mut face_value: F, number_of_dice: u32) -> Self
+    where
+        F: FnMut() -> FaceValue,
+    {
+        let mut dice = Vec::new();
+        for _ in 0..number_of_dice {
+            let die = Die::new(face_value());
+            dice.push(die);
+        }
+        Dice { dice }
     }
 
-    pub fn len(&self) -> usize {
-        self.dice.len()
+    pub fn pick<F>(&mut self, mut decide: F, max_hits: Option<usize>) -> Self
+    where
+        F: FnMut(FaceValue) -> bool,
+    {
+        let max_hits = max_hits.unwrap_or(self.dice.len());
+
+        let mut pick_counter = 0;
+        let mut keep = Vec::new();
+        let mut picked = Vec::new();
+        for die in self.dice.iter() {
+            if decide(die.value) && pick_counter < max_hits {
+                picked.push(*die);
+                pick_counter += 1;
+            } else {
+                keep.push(*die);
+            }
+        }
+        self.dice = keep;
+        picked.into()
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.dice.is_empty()
+    pub fn max(&self) -> Option<Die> {
+        self.dice.iter().max().copied()
     }
 
-    pub fn push(&mut self, die: Die) {
-        self.dice.push(die);
+    /// The number of dice per face, ones first.
+    pub fn face_counts(&self) -> [u8; 6] {
+        let mut counts = [0; 6];
+        for die in self.dice.iter() {
+            counts[die.value.as_u8() as usize - 1] += 1;
+        }
+        counts
+    }
+}
+
+fn draw_styled_die<D, T>(die: &D, cell: &mut T, style: RenderStyle) -> Result<(), T::Error>
+where
+    D: DieFace,
+    T: Display,
+{
+    if !style.underline {
+        return draw_die(die, cell, style);
+    }
+    let side_length = cell.size().width.min(cell.size().height);
+    let gap = utils::percent_of(side_length, UNDERLINE_GAP_IN_PERCENT).max(MIN_UNDERLINE_GAP);
+    let area = Rectangle::new(
+        Point::new(gap as i32, 0),
+        Size::new_equal(side_length - 2 * gap),
+    );
+    draw_die(die, &mut cell.cropped(&area), style)?;
+
+    let bottom = side_length as i32 - 1;
+    Line::new(
+        Point::new(gap as i32, bottom),
+        Point::new((side_length - gap) as i32 - 1, bottom),
+    )
+    .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+    .draw(cell)
+}
+
+fn draw_die<D, T>(die: &D, target: &mut T, style: RenderStyle) -> Result<(), T::Error>
+where
+    D: DieFace,
+    T: Display,
+{
+    if style.dimmed {
+        die.draw_themed(&mut Dimmed { target }, &style.theme, style.inverted)
+    } else {
+        die.draw_themed(target, &style.theme, style.inverted)
+    }
+}
+
+/// Passes on every other pixel, like a checkerboard, to dim whatever is drawn.
+struct Dimmed<'a, T> {
+    target: &'a mut T,
+}
+
+impl<T> OriginDimensions for Dimmed<'_, T>
+where
+    T: Display,
+{
+    fn size(&self) -> Size {
+        self.target.size()
+    }
+}
+
+impl<T> DrawTarget for Dimmed<'_, T>
+where
+    T: Display,
+{
+    type Color = BinaryColor;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.target.draw_iter(
+            pixels
+                .into_iter()
+                .filter(|Pixel(point, _)| (point.x + point.y) & 1 == 0),
+        )
     }
 }
 
@@ -128,8 +333,8 @@ fn find_best_grid(number_of_entries: u32, width: u32, height: u32) -> (u32, u32,
     (best_colums, best_rows, best_size)
 }
 
-impl From<Vec<Die>> for Dice {
-    fn from(value: Vec<Die>) -> Self {
+impl<D> From<Vec<D>> for Dice<D> {
+    fn from(value: Vec<D>) -> Self {
         Dice::from(value)
     }
 }
@@ -1,7 +1,12 @@
 use embedded_graphics::{
+    mono_font::MonoTextStyle,
     pixelcolor::BinaryColor,
     prelude::*,
-    primitives::{Circle, CornerRadii, PrimitiveStyle, Rectangle, RoundedRectangle},
+    primitives::{
+        Circle, CornerRadii, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, RoundedRectangle,
+        StrokeAlignment, Triangle,
+    },
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
 };
 
 use core::cmp::Ordering;
@@ -9,32 +14,142 @@ use rand::distr::{Distribution, StandardUniform};
 use rand::Rng;
 
 use crate::aliases::{Display, DrawTarget};
+use crate::polyhedral::FONTS;
 use crate::utils;
 
 const PADDING_IN_PERCENT: u32 = 3;
 const CORNER_RADIUS_IN_PERCENT: u32 = 6;
+const PIP_SIZE_IN_PERCENT: u32 = 13;
+/// How much of the face the numeral of a numeral face may take up.
+const NUMERAL_SIZE_IN_PERCENT: u32 = 60;
+
+/// What shows the value on a face.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Pips {
+    Round,
+    Square,
+    Diamond,
+    /// The value as a digit instead of pips.
+    Numeral,
+}
+
+/// The look of a `Die`, e.g. one of `DieStyle::THEMES`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct DieStyle {
+    /// Draws a filled die with blank pips, and an outlined one when it is drawn inverted.
+    pub solid: bool,
+    pub border_width: u32,
+    pub pips: Pips,
+    /// Zero for sharp corners.
+    pub corner_radius_in_percent: u32,
+    pub pip_size_in_percent: u32,
+}
+
+impl DieStyle {
+    pub const CLASSIC: Self = Self {
+        solid: false,
+        border_width: 1,
+        pips: Pips::Round,
+        corner_radius_in_percent: CORNER_RADIUS_IN_PERCENT,
+        pip_size_in_percent: PIP_SIZE_IN_PERCENT,
+    };
+
+    /// The themes to pick from on the settings screen, by name.
+    pub const THEMES: [(&'static str, DieStyle); 6] = [
+        ("Classic", DieStyle::CLASSIC),
+        ("Solid", DieStyle::CLASSIC.solid()),
+        (
+            "Bold",
+            DieStyle::CLASSIC.with_border_width(3).with_pip_size(15),
+        ),
+        ("Square", DieStyle::CLASSIC.with_pips(Pips::Square).sharp()),
+        (
+            "Diamond",
+            DieStyle::CLASSIC
+                .solid()
+                .with_pips(Pips::Diamond)
+                .with_corner_radius(12)
+                .with_pip_size(19),
+        ),
+        ("Numeral", DieStyle::CLASSIC.with_pips(Pips::Numeral)),
+    ];
+
+    pub const fn solid(self) -> Self {
+        Self {
+            solid: true,
+            ..self
+        }
+    }
+
+    pub const fn with_border_width(self, border_width: u32) -> Self {
+        Self {
+            border_width,
+            ..self
+        }
+    }
+
+    pub const fn with_pips(self, pips: Pips) -> Self {
+        Self { pips, ..self }
+    }
+
+    pub const fn sharp(self) -> Self {
+        self.with_corner_radius(0)
+    }
+
+    pub const fn with_corner_radius(self, corner_radius_in_percent: u32) -> Self {
+        Self {
+            corner_radius_in_percent,
+            ..self
+        }
+    }
+
+    pub const fn with_pip_size(self, pip_size_in_percent: u32) -> Self {
+        Self {
+            pip_size_in_percent,
+            ..self
+        }
+    }
+}
+
+impl Default for DieStyle {
+    fn default() -> Self {
+        DieStyle::CLASSIC
+    }
+}
 
 struct Face {
     size: u32,
+    corner_radius_in_percent: u32,
     style: PrimitiveStyle<BinaryColor>,
 }
 
 impl Face {
-    fn new(size: u32) -> Self {
-        let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
-        Self { size, style }
+    fn new(size: u32, style: &DieStyle, filled: bool) -> Self {
+        let primitive_style = if filled {
+            PrimitiveStyle::with_fill(BinaryColor::On)
+        } else {
+            PrimitiveStyleBuilder::new()
+                .stroke_color(BinaryColor::On)
+                .stroke_width(style.border_width)
+                .stroke_alignment(StrokeAlignment::Inside)
+                .build()
+        };
+        Self {
+            size,
+            corner_radius_in_percent: style.corner_radius_in_percent,
+            style: primitive_style,
+        }
     }
 
     fn draw<T>(&self, target: &mut T) -> Result<(), T::Error>
     where
         T: DrawTarget,
     {
-        let radius = utils::percent_of(self.size, CORNER_RADIUS_IN_PERCENT);
+        let radius = utils::percent_of(self.size, self.corner_radius_in_percent);
         RoundedRectangle::new(
             Rectangle::new(Point::new(0, 0), Size::new(self.size, self.size)),
             CornerRadii::new(Size::new(radius, radius)),
         )
-        .translate(Point::new(0, 0))
         .into_styled(self.style)
         .draw(target)
     }
@@ -42,25 +157,55 @@ impl Face {
 
 struct Pip {
     size: u32,
-    style: PrimitiveStyle<BinaryColor>,
+    shape: Pips,
+    color: BinaryColor,
     point: PipPoint,
 }
 
 impl Pip {
-    fn new(face_side_length: u32) -> Self {
-        let size = utils::percent_of_to_nearest_odd(face_side_length, 13);
+    fn new(face_side_length: u32, style: &DieStyle, filled: bool) -> Self {
+        let size = utils::percent_of_to_nearest_odd(face_side_length, style.pip_size_in_percent);
         let point = PipPoint::new(face_side_length, size);
-        let style = PrimitiveStyle::with_fill(BinaryColor::On);
-        Self { size, style, point }
+        let color = if filled {
+            BinaryColor::Off
+        } else {
+            BinaryColor::On
+        };
+        Self {
+            size,
+            shape: style.pips,
+            color,
+            point,
+        }
     }
 
     fn draw<T>(&self, target: &mut T, point: Point) -> Result<(), T::Error>
     where
         T: DrawTarget,
     {
-        Circle::new(point, self.size)
-            .into_styled(self.style)
-            .draw(target)
+        let style = PrimitiveStyle::with_fill(self.color);
+        match self.shape {
+            Pips::Round | Pips::Numeral => Circle::new(point, self.size)
+                .into_styled(style)
+                .draw(target),
+            Pips::Square => Rectangle::new(point, Size::new_equal(self.size))
+                .into_styled(style)
+                .draw(target),
+            Pips::Diamond => {
+                let last = self.size as i32 - 1;
+                let center = last / 2;
+                let top = point + Point::new(center, 0);
+                let right = point + Point::new(last, center);
+                let bottom = point + Point::new(center, last);
+                let left = point + Point::new(0, center);
+                Triangle::new(top, right, left)
+                    .into_styled(style)
+                    .draw(target)?;
+                Triangle::new(bottom, right, left)
+                    .into_styled(style)
+                    .draw(target)
+            }
+        }
     }
 
     fn draw_center_pip<T>(&self, target: &mut T) -> Result<(), T::Error>
@@ -169,91 +314,92 @@ impl PipPoint {
     }
 }
 
-fn draw_one<T>(target: &mut T, side_length: u32) -> Result<(), T::Error>
+fn draw_one<T>(target: &mut T, pip: &Pip) -> Result<(), T::Error>
 where
     T: DrawTarget,
 {
-    let pip = Pip::new(side_length);
-
-    pip.draw_center_pip(target)?;
-
-    let face = Face::new(side_length);
-    face.draw(target)
+    pip.draw_center_pip(target)
 }
 
-fn draw_two<T>(target: &mut T, side_length: u32) -> Result<(), T::Error>
+fn draw_two<T>(target: &mut T, pip: &Pip) -> Result<(), T::Error>
 where
     T: DrawTarget,
 {
-    let pip = Pip::new(side_length);
-
     pip.draw_upper_left_pip(target)?;
-    pip.draw_bottom_right_pip(target)?;
-
-    let face = Face::new(side_length);
-    face.draw(target)
+    pip.draw_bottom_right_pip(target)
 }
 
-fn draw_three<T>(target: &mut T, side_length: u32) -> Result<(), T::Error>
+fn draw_three<T>(target: &mut T, pip: &Pip) -> Result<(), T::Error>
 where
     T: DrawTarget,
 {
-    let pip = Pip::new(side_length);
-
     pip.draw_center_pip(target)?;
     pip.draw_upper_left_pip(target)?;
-    pip.draw_bottom_right_pip(target)?;
-
-    let face = Face::new(side_length);
-    face.draw(target)
+    pip.draw_bottom_right_pip(target)
 }
 
-fn draw_four<T>(target: &mut T, side_length: u32) -> Result<(), T::Error>
+fn draw_four<T>(target: &mut T, pip: &Pip) -> Result<(), T::Error>
 where
     T: DrawTarget,
 {
-    let pip = Pip::new(side_length);
-
     pip.draw_upper_left_pip(target)?;
     pip.draw_upper_right_pip(target)?;
     pip.draw_bottom_right_pip(target)?;
-    pip.draw_bottom_left_pip(target)?;
-
-    let face = Face::new(side_length);
-    face.draw(target)
+    pip.draw_bottom_left_pip(target)
 }
 
-fn draw_five<T>(target: &mut T, side_length: u32) -> Result<(), T::Error>
+fn draw_five<T>(target: &mut T, pip: &Pip) -> Result<(), T::Error>
 where
     T: DrawTarget,
 {
-    let pip = Pip::new(side_length);
-
     pip.draw_center_pip(target)?;
     pip.draw_upper_left_pip(target)?;
     pip.draw_upper_right_pip(target)?;
     pip.draw_bottom_right_pip(target)?;
-    pip.draw_bottom_left_pip(target)?;
-
-    let face = Face::new(side_length);
-    face.draw(target)
+    pip.draw_bottom_left_pip(target)
 }
 
-fn draw_six<T>(target: &mut T, side_length: u32) -> Result<(), T::Error>
+fn draw_six<T>(target: &mut T, pip: &Pip) -> Result<(), T::Error>
 where
     T: DrawTarget,
 {
-    let pip = Pip::new(side_length);
-
     pip.draw_upper_left_pip(target)?;
     pip.draw_upper_right_pip(target)?;
     pip.draw_bottom_right_pip(target)?;
     pip.draw_bottom_left_pip(target)?;
     pip.draw_center_left_pip(target)?;
-    pip.draw_center_right_pip(target)?;
+    pip.draw_center_right_pip(target)
+}
 
-    let face = Face::new(side_length);
-    face.draw(target)
+/// The value as a digit in the middle of the face, the largest one that fits.
+fn draw_numeral<T>(
+    target: &mut T,
+    face_side_length: u32,
+    value: FaceValue,
+    color: BinaryColor,
+) -> Result<(), T::Error>
+where
+    T: DrawTarget,
+{
+    const DIGITS: [&str; 6] = ["1", "2", "3", "4", "5", "6"];
+    let room = utils::percent_of(face_side_length, NUMERAL_SIZE_IN_PERCENT);
+    let font = FONTS
+        .iter()
+        .find(|font| font.character_size.width <= room && font.character_size.height <= room)
+        .unwrap_or(&FONTS[FONTS.len() - 1]);
+    let text_style = TextStyleBuilder::new()
+        .alignment(Alignment::Center)
+        .baseline(Baseline::Middle)
+        .build();
+    let center = (face_side_length as i32 - 1) / 2;
+    Text::with_text_style(
+        DIGITS[value.as_u8() as usize - 1],
+        Point::new(center, center),
+        MonoTextStyle::new(font, color),
+        text_style,
+    )
+    .draw(target)?;
+    Ok(())
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
@@ -279,6 +425,43 @@ impl FaceValue {
     }
 }
 
+/// A die that can be drawn into a square target, e.g. a cell of the `Dice` grid.
+pub trait DieFace {
+    fn draw<T>(&self, target: &mut T) -> Result<(), T::Error>
+    where
+        T: Display;
+
+    /// Draws the die filled, for selected dice.
+    fn draw_inverted<T>(&self, target: &mut T) -> Result<(), T::Error>
+    where
+        T: Display;
+
+    /// Draws the die in a theme, inverted or not. Dice with a look of their own ignore it.
+    fn draw_themed<T>(
+        &self,
+        target: &mut T,
+        _style: &DieStyle,
+        inverted: bool,
+    ) -> Result<(), T::Error>
+    where
+        T: Display,
+    {
+        if inverted {
+            self.draw_inverted(target)
+        } else {
+            self.draw(target)
+        }
+    }
+}
+
+/// A die that can show any of its faces while it tumbles, see `RollAnimation`.
+pub trait RandomFace: Sized {
+    /// The same kind of die showing a random face.
+    fn random_face<R>(&self, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized;
+}
+
 #[derive(Eq, Copy, Clone)]
 pub struct Die {
     pub value: FaceValue,
@@ -290,6 +473,21 @@ impl Die {
     }
 
     pub fn draw<T>(&mut self, target: &mut T) -> Result<(), T::Error>
+    where
+        T: Display,
+    {
+        self.draw_face(target, &DieStyle::CLASSIC, false)
+    }
+
+    /// Draws a filled face with blank pips.
+    pub fn draw_inverted<T>(&mut self, target: &mut T) -> Result<(), T::Error>
+    where
+        T: Display,
+    {
+        self.draw_face(target, &DieStyle::CLASSIC, true)
+    }
+
+    fn draw_face<T>(&self, target: &mut T, style: &DieStyle, inverted: bool) -> Result<(), T::Error>
     where
         T: Display,
     {
@@ -298,17 +496,61 @@ impl Die {
         let face_side_length = target_side_length - 2 * padding;
         let mut padded_target = target.translated(Point::new(padding as i32, padding as i32));
 
+        let filled = style.solid != inverted;
+        Face::new(face_side_length, style, filled).draw(&mut padded_target)?;
+        let pip = Pip::new(face_side_length, style, filled);
+
+        if style.pips == Pips::Numeral {
+            return draw_numeral(&mut padded_target, face_side_length, self.value, pip.color);
+        }
         match &self.value {
-            FaceValue::One => draw_one(&mut padded_target, face_side_length),
-            FaceValue::Two => draw_two(&mut padded_target, face_side_length),
-            FaceValue::Three => draw_three(&mut padded_target, face_side_length),
-            FaceValue::Four => draw_four(&mut padded_target, face_side_length),
-            FaceValue::Five => draw_five(&mut padded_target, face_side_length),
-            FaceValue::Six => draw_six(&mut padded_target, face_side_length),
+            FaceValue::One => draw_one(&mut padded_target, &pip),
+            FaceValue::Two => draw_two(&mut padded_target, &pip),
+            FaceValue::Three => draw_three(&mut padded_target, &pip),
+            FaceValue::Four => draw_four(&mut padded_target, &pip),
+            FaceValue::Five => draw_five(&mut padded_target, &pip),
+            FaceValue::Six => draw_six(&mut padded_target, &pip),
         }
     }
 }
 
+impl DieFace for Die {
+    fn draw<T>(&self, target: &mut T) -> Result<(), T::Error>
+    where
+        T: Display,
+    {
+        self.draw_face(target, &DieStyle::CLASSIC, false)
+    }
+
+    fn draw_inverted<T>(&self, target: &mut T) -> Result<(), T::Error>
+    where
+        T: Display,
+    {
+        self.draw_face(target, &DieStyle::CLASSIC, true)
+    }
+
+    fn draw_themed<T>(
+        &self,
+        target: &mut T,
+        style: &DieStyle,
+        inverted: bool,
+    ) -> Result<(), T::Error>
+    where
+        T: Display,
+    {
+        self.draw_face(target, style, inverted)
+    }
+}
+
+impl RandomFace for Die {
+    fn random_face<R>(&self, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        Die::new(rng.random())
+    }
+}
+
 impl PartialEq for Die {
     fn eq(&self, other: &Self) -> bool {
         self.value == other.value
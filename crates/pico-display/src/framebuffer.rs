@@ -0,0 +1,227 @@
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+
+/// A monochrome frame packed to a bit per pixel, `WIDTH` pixels wide and `PAGES` times eight
+/// pixels high. The bytes are laid out like the RAM of an SSD1306: each page is a row of bytes,
+/// one per column, with the topmost pixel of the column in the lowest bit.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct PackedFrame<const WIDTH: usize, const PAGES: usize> {
+    pages: [[u8; WIDTH]; PAGES],
+}
+
+impl<const WIDTH: usize, const PAGES: usize> PackedFrame<WIDTH, PAGES> {
+    pub const HEIGHT: usize = PAGES * 8;
+
+    /// A frame with every pixel off.
+    pub const fn new() -> Self {
+        Self {
+            pages: [[0; WIDTH]; PAGES],
+        }
+    }
+
+    pub fn is_on(&self, point: Point) -> bool {
+        self.bit_of(point)
+            .is_some_and(|(page, column, bit)| self.pages[page][column] & bit != 0)
+    }
+
+    /// The bytes of the frame page by page, ready to be sent to the RAM of the display.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.pages.as_flattened()
+    }
+
+    /// The pixels that are on, leaving out the ones that are off.
+    pub fn lit_pixels(&self) -> impl Iterator<Item = Pixel<BinaryColor>> + '_ {
+        self.into_iter().filter(|Pixel(_, color)| color.is_on())
+    }
+
+    fn bit_of(&self, point: Point) -> Option<(usize, usize, u8)> {
+        let (x, y) = (
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+        );
+        if x >= WIDTH || y >= Self::HEIGHT {
+            return None;
+        }
+        Some((y / 8, x, 1 << (y % 8)))
+    }
+}
+
+impl<const WIDTH: usize, const PAGES: usize> Default for PackedFrame<WIDTH, PAGES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: usize, const PAGES: usize> OriginDimensions for PackedFrame<WIDTH, PAGES> {
+    fn size(&self) -> Size {
+        Size::new(WIDTH as u32, Self::HEIGHT as u32)
+    }
+}
+
+impl<const WIDTH: usize, const PAGES: usize> DrawTarget for PackedFrame<WIDTH, PAGES> {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some((page, column, bit)) = self.bit_of(point) {
+                if color.is_on() {
+                    self.pages[page][column] |= bit;
+                } else {
+                    self.pages[page][column] &= !bit;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let byte = if color.is_on() { 0xff } else { 0 };
+        self.pages = [[byte; WIDTH]; PAGES];
+        Ok(())
+    }
+}
+
+/// Every pixel of a frame, row by row from the top left corner.
+pub struct Pixels<'a, const WIDTH: usize, const PAGES: usize> {
+    frame: &'a PackedFrame<WIDTH, PAGES>,
+    index: usize,
+}
+
+impl<const WIDTH: usize, const PAGES: usize> Iterator for Pixels<'_, WIDTH, PAGES> {
+    type Item = Pixel<BinaryColor>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= WIDTH * PackedFrame::<WIDTH, PAGES>::HEIGHT {
+            return None;
+        }
+        let point = Point::new((self.index % WIDTH) as i32, (self.index / WIDTH) as i32);
+        self.index += 1;
+        Some(Pixel(point, BinaryColor::from(self.frame.is_on(point))))
+    }
+}
+
+impl<'a, const WIDTH: usize, const PAGES: usize> IntoIterator for &'a PackedFrame<WIDTH, PAGES> {
+    type Item = Pixel<BinaryColor>;
+    type IntoIter = Pixels<'a, WIDTH, PAGES>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Pixels {
+            frame: self,
+            index: 0,
+        }
+    }
+}
+
+/// A frame that remembers which of its bytes changed since they were last sent to the display,
+/// for every page the columns from the first to the last changed one. Drawing a pixel the way
+/// it already is changes nothing, so only what differs on screen needs to be sent again.
+pub struct DirtyFrame<const WIDTH: usize, const PAGES: usize> {
+    frame: PackedFrame<WIDTH, PAGES>,
+    dirty: [Option<(usize, usize)>; PAGES],
+}
+
+impl<const WIDTH: usize, const PAGES: usize> DirtyFrame<WIDTH, PAGES> {
+    /// A blank frame that is dirty all over, as what the display shows is not known yet.
+    pub const fn new() -> Self {
+        Self {
+            frame: PackedFrame::new(),
+            dirty: [Some((0, WIDTH - 1)); PAGES],
+        }
+    }
+
+    pub fn frame(&self) -> &PackedFrame<WIDTH, PAGES> {
+        &self.frame
+    }
+
+    /// Replaces the whole frame, only marking the bytes that differ. Unlike clearing and
+    /// redrawing, bytes that come out the same are not sent again.
+    pub fn copy_from(&mut self, frame: &PackedFrame<WIDTH, PAGES>) {
+        for page in 0..PAGES {
+            for column in 0..WIDTH {
+                self.set_byte(page, column, frame.pages[page][column]);
+            }
+        }
+    }
+
+    /// The changed part of every page from the top: the page, its first changed column and
+    /// the bytes from there up to the last changed one.
+    pub fn dirty_spans(&self) -> impl Iterator<Item = (usize, usize, &[u8])> + '_ {
+        self.dirty.iter().enumerate().filter_map(|(page, dirty)| {
+            dirty.map(|(first, last)| (page, first, &self.frame.pages[page][first..=last]))
+        })
+    }
+
+    pub fn dirty_bytes(&self) -> usize {
+        self.dirty_spans().map(|(_, _, bytes)| bytes.len()).sum()
+    }
+
+    /// Forgets about the changes once they are sent.
+    pub fn mark_clean(&mut self) {
+        self.dirty = [None; PAGES];
+    }
+
+    /// Sends the whole frame with the next flush, e.g. after the display was reset.
+    pub fn mark_all_dirty(&mut self) {
+        self.dirty = [Some((0, WIDTH - 1)); PAGES];
+    }
+
+    fn set_byte(&mut self, page: usize, column: usize, byte: u8) {
+        if self.frame.pages[page][column] == byte {
+            return;
+        }
+        self.frame.pages[page][column] = byte;
+        self.dirty[page] = Some(match self.dirty[page] {
+            Some((first, last)) => (first.min(column), last.max(column)),
+            None => (column, column),
+        });
+    }
+}
+
+impl<const WIDTH: usize, const PAGES: usize> Default for DirtyFrame<WIDTH, PAGES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WIDTH: usize, const PAGES: usize> OriginDimensions for DirtyFrame<WIDTH, PAGES> {
+    fn size(&self) -> Size {
+        self.frame.size()
+    }
+}
+
+impl<const WIDTH: usize, const PAGES: usize> DrawTarget for DirtyFrame<WIDTH, PAGES> {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some((page, column, bit)) = self.frame.bit_of(point) {
+                let byte = self.frame.pages[page][column];
+                let drawn = if color.is_on() {
+                    byte | bit
+                } else {
+                    byte & !bit
+                };
+                self.set_byte(page, column, drawn);
+            }
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let byte = if color.is_on() { 0xff } else { 0 };
+        for page in 0..PAGES {
+            for column in 0..WIDTH {
+                self.set_byte(page, column, byte);
+            }
+        }
+        Ok(())
+    }
+}
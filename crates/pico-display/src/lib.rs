@@ -2,6 +2,10 @@
 mod utils;
 
 pub mod aliases;
+pub mod animation;
 pub mod dice;
 pub mod die;
+pub mod framebuffer;
 pub mod messages;
+pub mod polyhedral;
+pub mod scorecard;
@@ -1,12 +1,19 @@
+extern crate alloc;
+use alloc::vec::Vec;
+use embedded_graphics::geometry::Size;
 use embedded_graphics::pixelcolor::BinaryColor;
 use embedded_graphics::primitives::Rectangle;
-use u8g2_fonts::fonts::{u8g2_font_logisoso22_tr, u8g2_font_t0_12_tr};
+use u8g2_fonts::fonts::{
+    u8g2_font_5x8_mf, u8g2_font_5x8_tr, u8g2_font_logisoso22_tr, u8g2_font_t0_12_tr,
+};
 use u8g2_fonts::types::{FontColor, HorizontalAlignment, VerticalPosition};
 use u8g2_fonts::FontRenderer;
 
 use crate::aliases::DrawTarget;
 use u8g2_fonts::Error;
 
+/// Renders the message centered in the big font, or in a smaller one where it would not fit,
+/// like two lines on a display of 32 pixels.
 pub fn big_centered_message<T>(
     message: &str,
     target: &mut T,
@@ -15,6 +22,36 @@ where
     T: DrawTarget,
 {
     let font = FontRenderer::new::<u8g2_font_logisoso22_tr>();
+    if !fits_centered(&font, message, target) {
+        return medium_sized_centered_message(message, target);
+    }
+    render_centered(&font, message, target)
+}
+
+/// Renders the message centered in the medium font, or in the small one where it would not
+/// fit.
+pub fn medium_sized_centered_message<T>(
+    message: &str,
+    target: &mut T,
+) -> Result<Option<Rectangle>, Error<T::Error>>
+where
+    T: DrawTarget,
+{
+    let font = FontRenderer::new::<u8g2_font_t0_12_tr>();
+    if !fits_centered(&font, message, target) {
+        return render_centered(&FontRenderer::new::<u8g2_font_5x8_tr>(), message, target);
+    }
+    render_centered(&font, message, target)
+}
+
+fn render_centered<T>(
+    font: &FontRenderer,
+    message: &str,
+    target: &mut T,
+) -> Result<Option<Rectangle>, Error<T::Error>>
+where
+    T: DrawTarget,
+{
     font.render_aligned(
         message,
         target.bounding_box().center(),
@@ -25,20 +62,97 @@ where
     )
 }
 
-pub fn medium_sized_centered_message<T>(
+/// Whether the message centered in the font stays within the target. A glyph missing from the
+/// font counts as fitting, rendering reports it.
+fn fits_centered<T>(font: &FontRenderer, message: &str, target: &T) -> bool
+where
+    T: DrawTarget,
+{
+    let area = target.bounding_box();
+    match font.get_rendered_dimensions_aligned(
+        message,
+        area.center(),
+        VerticalPosition::Center,
+        HorizontalAlignment::Center,
+    ) {
+        Ok(Some(rendered)) => {
+            area.contains(rendered.top_left)
+                && rendered
+                    .bottom_right()
+                    .is_none_or(|corner| area.contains(corner))
+        }
+        Ok(None) | Err(_) => true,
+    }
+}
+
+/// Renders a monospaced message from the upper left corner, fitting lines of 25 characters
+/// on a display 128 pixels wide, seven of them when it is 64 pixels high and three at 32. Blank
+/// lines only space the text out, so they are left out where not all lines would fit.
+pub fn small_message<T>(message: &str, target: &mut T) -> Result<Option<Rectangle>, Error<T::Error>>
+where
+    T: DrawTarget,
+{
+    let font = FontRenderer::new::<u8g2_font_5x8_tr>();
+    if !small_message_fits(message, target.bounding_box().size) {
+        let lines: Vec<&str> = message
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+        return render_from_top_left(&font, &lines.join("\n"), target);
+    }
+    render_from_top_left(&font, message, target)
+}
+
+/// The lines of the small font that fit on a target of `size`.
+pub fn small_message_rows(size: Size) -> usize {
+    let font = FontRenderer::new::<u8g2_font_5x8_tr>();
+    (size.height / font.get_default_line_height()) as usize
+}
+
+/// Whether every line of the message, the blank ones included, fits on a target of `size`.
+pub fn small_message_fits(message: &str, size: Size) -> bool {
+    message.lines().count() <= small_message_rows(size)
+}
+
+fn render_from_top_left<T>(
+    font: &FontRenderer,
     message: &str,
     target: &mut T,
 ) -> Result<Option<Rectangle>, Error<T::Error>>
 where
     T: DrawTarget,
 {
-    let font = FontRenderer::new::<u8g2_font_t0_12_tr>();
     font.render_aligned(
         message,
-        target.bounding_box().center(),
-        VerticalPosition::Center,
-        HorizontalAlignment::Center,
+        target.bounding_box().top_left,
+        VerticalPosition::Top,
+        HorizontalAlignment::Left,
         FontColor::Transparent(BinaryColor::On),
         target,
     )
 }
+
+/// Renders a single line into the bottom right corner on a background of its own, so that it
+/// can be put on top of whatever is shown, like a status bar. The font is the small one in a
+/// variant with solid glyphs, as the transparent one cannot be drawn on a background.
+pub fn status_message<T>(
+    message: &str,
+    target: &mut T,
+) -> Result<Option<Rectangle>, Error<T::Error>>
+where
+    T: DrawTarget,
+{
+    let font = FontRenderer::new::<u8g2_font_5x8_mf>();
+    let bounding_box = target.bounding_box();
+    font.render_aligned(
+        message,
+        bounding_box.bottom_right().unwrap_or(bounding_box.top_left),
+        VerticalPosition::Bottom,
+        HorizontalAlignment::Right,
+        FontColor::WithBackground {
+            fg: BinaryColor::On,
+            bg: BinaryColor::Off,
+        },
+        target,
+    )
+}
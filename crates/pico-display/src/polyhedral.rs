@@ -0,0 +1,294 @@
+use core::fmt;
+use embedded_graphics::mono_font::ascii::{FONT_10X20, FONT_4X6, FONT_6X10, FONT_6X13, FONT_8X13};
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyle};
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Polyline, PrimitiveStyle};
+use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+use rand::distr::Distribution;
+use rand::Rng;
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use crate::aliases::{Display, DrawTarget};
+use crate::dice::Dice;
+use crate::die::{DieFace, RandomFace};
+use crate::utils;
+
+const PADDING_IN_PERCENT: u32 = 3;
+/// Largest first, the first one whose numeral fits the polygon is used.
+pub(crate) const FONTS: [&MonoFont; 5] =
+    [&FONT_10X20, &FONT_8X13, &FONT_6X13, &FONT_6X10, &FONT_4X6];
+
+/// The number of sides of a die, used as the distribution to roll it with, e.g.
+/// `rng.sample(Sides::D20)`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
+pub enum Sides {
+    D4,
+    D6,
+    D8,
+    D10,
+    D12,
+    D20,
+    D100,
+}
+
+impl Sides {
+    pub const ALL: [Sides; 7] = [
+        Sides::D4,
+        Sides::D6,
+        Sides::D8,
+        Sides::D10,
+        Sides::D12,
+        Sides::D20,
+        Sides::D100,
+    ];
+
+    pub fn count(&self) -> u8 {
+        match self {
+            Sides::D4 => 4,
+            Sides::D6 => 6,
+            Sides::D8 => 8,
+            Sides::D10 => 10,
+            Sides::D12 => 12,
+            Sides::D20 => 20,
+            Sides::D100 => 100,
+        }
+    }
+
+    pub fn from_count(count: u8) -> Option<Self> {
+        Sides::ALL.into_iter().find(|sides| sides.count() == count)
+    }
+
+    fn shape(&self) -> Shape {
+        match self {
+            Sides::D4 => Shape::Triangle,
+            Sides::D6 => Shape::Square,
+            Sides::D8 | Sides::D10 | Sides::D100 => Shape::Diamond,
+            Sides::D12 => Shape::Pentagon,
+            Sides::D20 => Shape::Hexagon,
+        }
+    }
+}
+
+impl fmt::Display for Sides {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "d{}", self.count())
+    }
+}
+
+impl Distribution<PolyhedralDie> for Sides {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PolyhedralDie {
+        PolyhedralDie::new(*self, rng.random_range(1..=self.count()))
+    }
+}
+
+/// The outline drawn around the numeral, the silhouette of the die lying on the table.
+#[derive(Copy, Clone)]
+enum Shape {
+    Triangle,
+    Square,
+    Diamond,
+    Pentagon,
+    Hexagon,
+}
+
+impl Shape {
+    /// The corners in thousandths of the side length of a square target.
+    fn corners(&self) -> &'static [(i32, i32)] {
+        match self {
+            Shape::Triangle => &[(500, 67), (1000, 933), (0, 933)],
+            Shape::Square => &[(0, 0), (1000, 0), (1000, 1000), (0, 1000)],
+            Shape::Diamond => &[(500, 0), (1000, 500), (500, 1000), (0, 500)],
+            Shape::Pentagon => &[(500, 24), (1000, 387), (809, 975), (191, 975), (0, 387)],
+            Shape::Hexagon => &[
+                (500, 0),
+                (933, 250),
+                (933, 750),
+                (500, 1000),
+                (67, 750),
+                (67, 250),
+            ],
+        }
+    }
+
+    /// The center and the size of the box the numeral has to fit in, in thousandths.
+    fn numeral_box(&self) -> ((i32, i32), (i32, i32)) {
+        match self {
+            Shape::Triangle => ((500, 640), (420, 300)),
+            Shape::Square => ((500, 500), (700, 700)),
+            Shape::Diamond => ((500, 500), (500, 500)),
+            Shape::Pentagon => ((500, 540), (600, 500)),
+            Shape::Hexagon => ((500, 500), (620, 560)),
+        }
+    }
+}
+
+/// A die of any number of sides, drawn as its numeral inside a polygon.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
+pub struct PolyhedralDie {
+    /// The face value from one up to the number of sides.
+    pub value: u8,
+    pub sides: Sides,
+}
+
+impl PolyhedralDie {
+    pub fn new(sides: Sides, value: u8) -> Self {
+        Self { value, sides }
+    }
+
+    fn draw_face<T>(&self, target: &mut T, inverted: bool) -> Result<(), T::Error>
+    where
+        T: Display,
+    {
+        let size = target.size();
+        let target_side_length = size.width.min(size.height);
+        let padding = utils::percent_of(target_side_length, PADDING_IN_PERCENT);
+        let side_length = (target_side_length - 2 * padding) as i32;
+        let mut target = target.translated(Point::new(padding as i32, padding as i32));
+        let scale = |thousandths: i32| (side_length - 1) * thousandths / 1000;
+
+        let shape = self.sides.shape();
+        let mut corners: Vec<Point> = shape
+            .corners()
+            .iter()
+            .map(|&(x, y)| Point::new(scale(x), scale(y)))
+            .collect();
+        corners.extend(corners.first().copied());
+        Polyline::new(&corners)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+            .draw(&mut target)?;
+        let numeral_color = if inverted {
+            fill_rows(&corners, &mut target)?;
+            BinaryColor::Off
+        } else {
+            BinaryColor::On
+        };
+
+        let ((center_x, center_y), (width, height)) = shape.numeral_box();
+        let digits = digits(self.value);
+        let font = FONTS
+            .iter()
+            .find(|font| {
+                let numeral_width = font.character_size.width * digits;
+                numeral_width as i32 <= scale(width)
+                    && font.character_size.height as i32 <= scale(height)
+            })
+            .unwrap_or(&FONTS[FONTS.len() - 1]);
+        let style = MonoTextStyle::new(font, numeral_color);
+        let text_style = TextStyleBuilder::new()
+            .alignment(Alignment::Center)
+            .baseline(Baseline::Middle)
+            .build();
+        let mut numeral = [0u8; 3];
+        Text::with_text_style(
+            format_numeral(self.value, &mut numeral),
+            Point::new(scale(center_x), scale(center_y)),
+            style,
+            text_style,
+        )
+        .draw(&mut target)?;
+        Ok(())
+    }
+}
+
+impl DieFace for PolyhedralDie {
+    fn draw<T>(&self, target: &mut T) -> Result<(), T::Error>
+    where
+        T: Display,
+    {
+        self.draw_face(target, false)
+    }
+
+    fn draw_inverted<T>(&self, target: &mut T) -> Result<(), T::Error>
+    where
+        T: Display,
+    {
+        self.draw_face(target, true)
+    }
+}
+
+impl RandomFace for PolyhedralDie {
+    fn random_face<R>(&self, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        rng.sample(self.sides)
+    }
+}
+
+impl Dice<PolyhedralDie> {
+    /// Rolls the given number of dice with the same number of sides.
+    pub fn roll_sides<R>(sides: Sides, number_of_dice: u32, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let mut dice = Dice::empty();
+        for _ in 0..number_of_dice {
+            dice.push(rng.sample(sides));
+        }
+        dice
+    }
+
+    pub fn total(&self) -> u32 {
+        self.dice.iter().map(|die| die.value as u32).sum()
+    }
+}
+
+/// Fills the convex polygon row by row, between the leftmost and rightmost edge.
+fn fill_rows<T>(corners: &[Point], target: &mut T) -> Result<(), T::Error>
+where
+    T: DrawTarget,
+{
+    let top = corners
+        .iter()
+        .map(|corner| corner.y)
+        .min()
+        .unwrap_or_default();
+    let bottom = corners
+        .iter()
+        .map(|corner| corner.y)
+        .max()
+        .unwrap_or_default();
+    for y in top..=bottom {
+        let crossings = corners.windows(2).filter_map(|edge| {
+            let (from, to) = (edge[0], edge[1]);
+            let (low, high) = if from.y <= to.y {
+                (from, to)
+            } else {
+                (to, from)
+            };
+            if y < low.y || y > high.y || low.y == high.y {
+                return None;
+            }
+            Some(low.x + (high.x - low.x) * (y - low.y) / (high.y - low.y))
+        });
+        let Some((left, right)) = crossings.fold(None, |row: Option<(i32, i32)>, x| match row {
+            Some((left, right)) => Some((left.min(x), right.max(x))),
+            None => Some((x, x)),
+        }) else {
+            continue;
+        };
+        target.draw_iter((left..=right).map(|x| Pixel(Point::new(x, y), BinaryColor::On)))?;
+    }
+    Ok(())
+}
+
+fn digits(value: u8) -> u32 {
+    match value {
+        0..=9 => 1,
+        10..=99 => 2,
+        _ => 3,
+    }
+}
+
+fn format_numeral(value: u8, buffer: &mut [u8; 3]) -> &str {
+    let digits = digits(value) as usize;
+    let mut rest = value;
+    for digit in buffer[..digits].iter_mut().rev() {
+        *digit = b'0' + rest % 10;
+        rest /= 10;
+    }
+    core::str::from_utf8(&buffer[..digits]).unwrap_or_default()
+}
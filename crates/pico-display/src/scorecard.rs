@@ -0,0 +1,91 @@
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use u8g2_fonts::fonts::u8g2_font_5x8_tr;
+use u8g2_fonts::types::{FontColor, HorizontalAlignment, VerticalPosition};
+use u8g2_fonts::{Error, FontRenderer};
+
+use crate::aliases::DrawTarget;
+
+pub const LINE_HEIGHT: u32 = 8;
+/// Twelve characters of the 5x8 font: a label of up to eight characters and a score of up to
+/// four digits, with a few pixels between columns.
+pub const COLUMN_WIDTH: u32 = 64;
+const SCORE_RIGHT_EDGE: i32 = 60;
+
+/// A single line of a scorecard, drawn as a dash while `score` is `None`.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Entry<'a> {
+    pub label: &'a str,
+    pub score: Option<u16>,
+}
+
+impl<'a> Entry<'a> {
+    pub const fn new(label: &'a str, score: Option<u16>) -> Self {
+        Self { label, score }
+    }
+}
+
+/// The number of entries that fit on the target at once, column by column.
+pub fn entries_per_page(size: Size) -> usize {
+    let columns = (size.width / COLUMN_WIDTH).max(1);
+    let rows = (size.height / LINE_HEIGHT).max(1);
+    (columns * rows) as usize
+}
+
+pub fn number_of_pages(number_of_entries: usize, size: Size) -> usize {
+    number_of_entries.div_ceil(entries_per_page(size)).max(1)
+}
+
+/// Draws one page of the entries in columns of labels and right aligned scores. Sixteen
+/// entries fit on a 128x64 display, half of them when drawn next to the dice.
+pub fn draw_scorecard<T>(
+    entries: &[Entry<'_>],
+    page: usize,
+    target: &mut T,
+) -> Result<(), Error<T::Error>>
+where
+    T: DrawTarget,
+{
+    let area = target.bounding_box();
+    let rows = (area.size.height / LINE_HEIGHT).max(1) as usize;
+    let per_page = entries_per_page(area.size);
+    let font = FontRenderer::new::<u8g2_font_5x8_tr>();
+    let color = FontColor::Transparent(BinaryColor::On);
+
+    let page = entries.chunks(per_page).nth(page).unwrap_or_default();
+    for (index, entry) in page.iter().enumerate() {
+        let column = (index / rows) as i32;
+        let row = (index % rows) as i32;
+        let top_left =
+            area.top_left + Point::new(column * COLUMN_WIDTH as i32, row * LINE_HEIGHT as i32);
+
+        font.render_aligned(
+            entry.label,
+            top_left,
+            VerticalPosition::Top,
+            HorizontalAlignment::Left,
+            color,
+            target,
+        )?;
+        let score_position = top_left + Point::new(SCORE_RIGHT_EDGE, 0);
+        match entry.score {
+            Some(score) => font.render_aligned(
+                format_args!("{score}"),
+                score_position,
+                VerticalPosition::Top,
+                HorizontalAlignment::Right,
+                color,
+                target,
+            )?,
+            None => font.render_aligned(
+                "-",
+                score_position,
+                VerticalPosition::Top,
+                HorizontalAlignment::Right,
+                color,
+                target,
+            )?,
+        };
+    }
+    Ok(())
+}
@@ -1,70 +1,156 @@
 extern crate alloc;
 
+use alloc::format;
+#[cfg(feature = "multiplayer")]
+use alloc::string::String;
 use alloc::string::ToString;
-use display_interface::DisplayError;
+#[cfg(feature = "multiplayer")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "multiplayer", feature = "provably-fair"))]
+use embedded_graphics::geometry::OriginDimensions;
 use embedded_graphics::{draw_target::DrawTarget, pixelcolor::BinaryColor};
 use {defmt_rtt as _, panic_probe as _};
 
-use embedded_graphics_framebuf::FrameBuf;
-
+#[cfg(feature = "provably-fair")]
+use game_logic::commitment::Commitment;
+#[cfg(feature = "multiplayer")]
+use game_logic::session::{RoundState, Session};
 use pico_display::messages;
 
-use crate::game::entities::{Display, DisplayFrame, GameState};
+use crate::game::entities::{DisplayFrame, GameState};
 use crate::game::error::FontError;
 
 pub struct FrameCache {
-    you_won_frame: FrameBuf<BinaryColor, DisplayFrame>,
-    fish_frame: FrameBuf<BinaryColor, DisplayFrame>,
-    score_frame: FrameBuf<BinaryColor, DisplayFrame>,
-    picked_dice_frame: FrameBuf<BinaryColor, DisplayFrame>,
+    you_won_frame: DisplayFrame,
+    lost_frame: DisplayFrame,
+    score_frame: DisplayFrame,
+    /// The page of the scoreboard last shown, rendered from the text of the pages.
+    #[cfg(feature = "multiplayer")]
+    scoreboard_frame: DisplayFrame,
+    #[cfg(feature = "multiplayer")]
+    scoreboard_pages: Vec<String>,
+    #[cfg(feature = "provably-fair")]
+    seed_frame: DisplayFrame,
 }
 
 impl FrameCache {
-    pub fn init() -> Result<Self, FontError> {
-        let buffer = [BinaryColor::Off; 8192];
-
-        let mut you_won_frame = new_frame_buffer(buffer);
-        let mut fish_frame = new_frame_buffer(buffer);
-
-        messages::big_centered_message("18!\nYou Win!", &mut you_won_frame)?;
-        messages::big_centered_message("Fish!", &mut fish_frame)?;
+    pub fn init(lost_message: &str) -> Result<Self, FontError> {
+        let mut lost_frame = DisplayFrame::new();
+        messages::big_centered_message(lost_message, &mut lost_frame)?;
 
         Ok(Self {
-            you_won_frame,
-            fish_frame,
-            score_frame: new_frame_buffer(buffer),
-            picked_dice_frame: new_frame_buffer(buffer),
+            you_won_frame: DisplayFrame::new(),
+            lost_frame,
+            score_frame: DisplayFrame::new(),
+            #[cfg(feature = "multiplayer")]
+            scoreboard_frame: DisplayFrame::new(),
+            #[cfg(feature = "multiplayer")]
+            scoreboard_pages: Vec::new(),
+            #[cfg(feature = "provably-fair")]
+            seed_frame: DisplayFrame::new(),
         })
     }
 
-    pub fn update_score_frame(&mut self, score: i8) -> Result<(), FontError> {
+    pub fn update_you_won_frame(&mut self, score: i16) -> Result<(), FontError> {
+        self.you_won_frame.clear(BinaryColor::Off)?;
+        messages::big_centered_message(
+            format!("{score}!\nYou Win!").as_str(),
+            &mut self.you_won_frame,
+        )?;
+        Ok(())
+    }
+
+    pub fn update_score_frame(&mut self, score: i16) -> Result<(), FontError> {
         self.score_frame.clear(BinaryColor::Off)?;
         messages::big_centered_message(score.to_string().as_str(), &mut self.score_frame)?;
         Ok(())
     }
 
-    pub fn replace_picked_dice_frame(&mut self, frame: DisplayFrame) {
-        self.picked_dice_frame = new_frame_buffer(frame);
+    /// Every page of the scoreboard repeats its heading above as many players as there are lines
+    /// left on the panel, so a 128x64 panel shows all of them on one page.
+    #[cfg(feature = "multiplayer")]
+    pub fn update_scoreboard_pages(&mut self, session: &Session) {
+        let heading = match (session.state(), session.winner()) {
+            (RoundState::Won(_), Some(winner)) => {
+                format!("{} wins round {}!\n", winner.name, session.round())
+            }
+            (RoundState::RollOff, _) => format!("Round {}: roll-off\n", session.round()),
+            _ => format!("Round {}\n", session.round()),
+        };
+        let lines: Vec<String> = session
+            .players()
+            .iter()
+            .enumerate()
+            .map(|(index, player)| {
+                let turn = if session.current_player() == Some(index) {
+                    '>'
+                } else {
+                    ' '
+                };
+                let score = player
+                    .score
+                    .map(|score| score.to_string())
+                    .unwrap_or(String::from("-"));
+                format!(
+                    "{turn}{:<12}{score:>4}{:>4}\n",
+                    player.name, player.rounds_won
+                )
+            })
+            .collect();
+
+        let players_per_page = messages::small_message_rows(self.scoreboard_frame.size())
+            .saturating_sub(1)
+            .max(1);
+        self.scoreboard_pages = lines
+            .chunks(players_per_page)
+            .map(|players| heading.clone() + &players.concat())
+            .collect();
+    }
+
+    #[cfg(feature = "provably-fair")]
+    pub fn update_seed_frame(&mut self, seed: u64) -> Result<(), FontError> {
+        let short = Commitment::to_seed(seed).short();
+        let reveal = format!(
+            "Seed of the game:\n{seed}\n\nHash before the game:\n{short}\n\nVerify it on a host."
+        );
+        let reveal = if messages::small_message_fits(&reveal, self.seed_frame.size()) {
+            reveal
+        } else {
+            format!("Seed of the game:\n{seed}\nHash: {short}")
+        };
+        self.seed_frame.clear(BinaryColor::Off)?;
+        messages::small_message(reveal.as_str(), &mut self.seed_frame)?;
+        Ok(())
     }
 
-    pub fn draw_message(
-        &self,
-        display: &mut Display,
-        game_state: &GameState,
-    ) -> Result<(), DisplayError> {
+    /// The message for the final state of a game.
+    pub fn message(&self, game_state: &GameState) -> Option<&DisplayFrame> {
         match game_state {
-            GameState::Won(_) => display.draw_iter(&self.you_won_frame),
-            GameState::Fish(_) => display.draw_iter(&self.fish_frame),
-            GameState::GameOver(_, _) => display.draw_iter(&self.score_frame),
-            _ => Ok(()),
+            GameState::Won(_) => Some(&self.you_won_frame),
+            GameState::Lost(_) => Some(&self.lost_frame),
+            GameState::GameOver(_) => Some(&self.score_frame),
+            _ => None,
         }
     }
 
-    pub fn draw_picked_dice(&self, display: &mut Display) -> Result<(), DisplayError> {
-        display.draw_iter(&self.picked_dice_frame)
+    /// The page of the scoreboard, if the players fill that many.
+    #[cfg(feature = "multiplayer")]
+    pub fn scoreboard(&mut self, page: usize) -> Result<Option<&DisplayFrame>, FontError> {
+        let Some(scoreboard) = self.scoreboard_pages.get(page) else {
+            return Ok(None);
+        };
+        self.scoreboard_frame.clear(BinaryColor::Off)?;
+        messages::small_message(scoreboard.as_str(), &mut self.scoreboard_frame)?;
+        Ok(Some(&self.scoreboard_frame))
     }
-}
 
-fn new_frame_buffer(frame: DisplayFrame) -> FrameBuf<BinaryColor, DisplayFrame> {
-    FrameBuf::new(frame, 128, 64)
+    #[cfg(feature = "multiplayer")]
+    pub fn scoreboard_pages(&self) -> usize {
+        self.scoreboard_pages.len()
+    }
+
+    #[cfg(feature = "provably-fair")]
+    pub fn seed(&self) -> &DisplayFrame {
+        &self.seed_frame
+    }
 }
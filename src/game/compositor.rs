@@ -0,0 +1,207 @@
+use defmt::debug;
+use display_interface::DisplayError;
+use embassy_futures::select::{select, Either};
+use embassy_sync::{blocking_mutex::raw::NoopRawMutex, channel::Channel, mutex::Mutex};
+use embassy_time::{Duration, Instant, Ticker};
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+use embedded_graphics::{draw_target::DrawTarget, pixelcolor::BinaryColor};
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+use pico_display::animation::RollAnimation;
+use pico_display::animation::FRAME_DURATION_IN_MS;
+#[cfg(feature = "multiplayer")]
+use pico_display::messages;
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+use rand::rngs::SmallRng;
+
+use crate::game::display::Display;
+use crate::game::entities::DisplayFrame;
+use crate::game::error::DrawError;
+
+/// Blinking inverts the display every second.
+const FRAMES_PER_BLINK: u32 = (1000 / FRAME_DURATION_IN_MS) as u32;
+
+/// Dice tumbling in place of the game layer until they land, with the generator of the faces
+/// they show on the way.
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+pub struct Tumble {
+    pub animation: RollAnimation,
+    pub small_rng: SmallRng,
+}
+
+pub enum RenderCommand {
+    /// The game layer was drawn anew.
+    ShowGame,
+    #[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+    Tumble(Tumble),
+    /// Shows the overlay layer in place of the game layer, or the game layer again.
+    ShowOverlay(bool),
+    /// A line in the bottom right corner, on top of the other layers.
+    #[cfg(feature = "multiplayer")]
+    Status(Option<&'static str>),
+    /// Inverts the whole display every second, or leaves it as it is.
+    Blink(bool),
+}
+
+type Layer = Mutex<NoopRawMutex, DisplayFrame>;
+type RenderChannel = Channel<NoopRawMutex, RenderCommand, 8>;
+
+/// What the tasks share with the renderer, the only one to draw to the display: the layers
+/// they draw into and the commands telling it what to show. Frames are composed at the steady
+/// rate of the animations, so that a frame always shows a single state of every layer.
+pub struct Renderer {
+    game: Layer,
+    overlay: Layer,
+    commands: RenderChannel,
+}
+
+impl Renderer {
+    pub const fn new() -> Self {
+        Self {
+            game: Mutex::new(DisplayFrame::new()),
+            overlay: Mutex::new(DisplayFrame::new()),
+            commands: Channel::new(),
+        }
+    }
+
+    /// Draws into the game layer, which is shown from the next `show_game` on.
+    pub async fn draw_game<R>(&self, draw: impl FnOnce(&mut DisplayFrame) -> R) -> R {
+        draw(&mut *self.game.lock().await)
+    }
+
+    pub async fn show_game(&self) {
+        self.commands.send(RenderCommand::ShowGame).await;
+    }
+
+    /// Tumbles the dice, the game layer is shown again once they landed.
+    #[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+    pub async fn tumble(&self, tumble: Tumble) {
+        self.commands.send(RenderCommand::Tumble(tumble)).await;
+    }
+
+    /// Copies the frame into the overlay layer and shows it in place of the game.
+    pub async fn show_overlay(&self, frame: &DisplayFrame) {
+        let started_at = Instant::now();
+        *self.overlay.lock().await = *frame;
+        self.commands.send(RenderCommand::ShowOverlay(true)).await;
+        debug!(
+            "Overlay handed over in {} mus",
+            started_at.elapsed().as_micros()
+        );
+    }
+
+    pub async fn hide_overlay(&self) {
+        self.commands.send(RenderCommand::ShowOverlay(false)).await;
+    }
+
+    #[cfg(feature = "multiplayer")]
+    pub async fn set_status(&self, status: Option<&'static str>) {
+        self.commands.send(RenderCommand::Status(status)).await;
+    }
+
+    pub async fn set_blinking(&self, blinking: bool) {
+        self.commands.send(RenderCommand::Blink(blinking)).await;
+    }
+
+    /// Takes over the display for good, composing a frame whenever a layer changed.
+    pub async fn run(&self, display: &mut Display) -> ! {
+        let mut compositor = Compositor::default();
+        let mut ticker = Ticker::every(Duration::from_millis(FRAME_DURATION_IN_MS));
+        loop {
+            match select(ticker.next(), self.commands.receive()).await {
+                Either::First(_) => compositor.next_frame(self, display).await.unwrap(),
+                Either::Second(command) => compositor.apply(command),
+            }
+        }
+    }
+}
+
+/// What the renderer shows, changed by the commands in between two frames.
+#[derive(Default)]
+struct Compositor {
+    overlay_shown: bool,
+    #[cfg(feature = "multiplayer")]
+    status: Option<&'static str>,
+    #[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+    tumble: Option<Tumble>,
+    blinking: bool,
+    inverted: bool,
+    shown_inverted: bool,
+    frame: u32,
+    changed: bool,
+}
+
+impl Compositor {
+    fn apply(&mut self, command: RenderCommand) {
+        match command {
+            RenderCommand::ShowGame => {}
+            #[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+            RenderCommand::Tumble(tumble) => self.tumble = Some(tumble),
+            RenderCommand::ShowOverlay(shown) => self.overlay_shown = shown,
+            #[cfg(feature = "multiplayer")]
+            RenderCommand::Status(status) => self.status = status,
+            RenderCommand::Blink(blinking) => {
+                self.blinking = blinking;
+                self.inverted = blinking;
+                self.frame = 0;
+            }
+        }
+        self.changed = true;
+    }
+
+    async fn next_frame(
+        &mut self,
+        renderer: &Renderer,
+        display: &mut Display,
+    ) -> Result<(), DrawError<DisplayError>> {
+        self.frame = self.frame.wrapping_add(1);
+        if self.blinking && self.frame.is_multiple_of(FRAMES_PER_BLINK) {
+            self.inverted = !self.inverted;
+        }
+        if self.inverted != self.shown_inverted {
+            display.set_invert(self.inverted).await?;
+            self.shown_inverted = self.inverted;
+        }
+
+        if !self.draw_tumble(display)? {
+            if !self.changed {
+                return Ok(());
+            }
+            let layer = if self.overlay_shown {
+                &renderer.overlay
+            } else {
+                &renderer.game
+            };
+            display.show(&*layer.lock().await);
+        }
+        #[cfg(feature = "multiplayer")]
+        if let Some(status) = self.status {
+            messages::status_message(status, display)?;
+        }
+        display.flush().await?;
+        self.changed = false;
+        Ok(())
+    }
+
+    /// Draws the next frame of the tumbling dice, if there are any that did not land yet.
+    #[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+    fn draw_tumble(&mut self, display: &mut Display) -> Result<bool, DisplayError> {
+        let Some(tumble) = &mut self.tumble else {
+            return Ok(false);
+        };
+        if tumble.animation.is_landed() {
+            // the game layer shows the dice as they landed
+            self.tumble = None;
+            self.changed = true;
+            return Ok(false);
+        }
+        display.clear(BinaryColor::Off)?;
+        tumble.animation.draw(display)?;
+        tumble.animation.advance(&mut tumble.small_rng);
+        Ok(true)
+    }
+
+    #[cfg(any(feature = "manual-pick", feature = "pig"))]
+    fn draw_tumble(&mut self, _display: &mut Display) -> Result<bool, DisplayError> {
+        Ok(false)
+    }
+}
@@ -0,0 +1,126 @@
+use display_interface::DisplayError;
+use embassy_rp::{
+    i2c::{self, I2c},
+    peripherals::I2C1,
+};
+use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
+use pico_display::framebuffer::DirtyFrame;
+#[cfg(feature = "panel-128x32")]
+use ssd1306::size::DisplaySize128x32;
+#[cfg(not(feature = "panel-128x32"))]
+use ssd1306::size::DisplaySize128x64;
+use ssd1306::{
+    command::AddrMode, mode::BasicMode, prelude::I2CInterface, rotation::DisplayRotation,
+    size::DisplaySizeAsync, I2CDisplayInterface, Ssd1306Async,
+};
+
+use crate::game::entities::DisplayFrame;
+
+#[cfg(not(feature = "panel-128x32"))]
+type PanelSize = DisplaySize128x64;
+#[cfg(feature = "panel-128x32")]
+type PanelSize = DisplaySize128x32;
+
+/// The width of the panel in pixels, and of every frame drawn for it.
+pub const WIDTH: usize = <PanelSize as DisplaySizeAsync>::WIDTH as usize;
+/// The rows of eight pixels of the panel.
+pub const PAGES: usize = <PanelSize as DisplaySizeAsync>::HEIGHT as usize / 8;
+
+/// The RAM of the SH1106 is 132 columns wide, with the 128 of the panel in the middle.
+#[cfg(feature = "sh1106")]
+const SH1106_COLUMN_OFFSET: u8 = 2;
+
+/// The SSD1306 driver also drives the SSD1309 and the SH1106, which take the same commands
+/// for all that is sent here. Only the SH1106 lacks the draw areas of the horizontal
+/// addressing mode, so it is sent a page at a time instead.
+type Controller = Ssd1306Async<I2CInterface<I2c<'static, I2C1, i2c::Async>>, PanelSize, BasicMode>;
+
+/// The panel drawn to through a frame in memory. `flush` only sends the pages and columns
+/// that changed since the last flush, instead of the whole frame over I2C. A 128x64 SSD1306
+/// is driven unless built with `ssd1309`, `panel-128x32` for the smaller SSD1306, or with
+/// `sh1106` for that controller.
+pub struct Display {
+    controller: Controller,
+    frame: DirtyFrame<WIDTH, PAGES>,
+}
+
+impl Display {
+    pub fn new(i2c: I2c<'static, I2C1, i2c::Async>) -> Self {
+        let interface = I2CDisplayInterface::new(i2c);
+        Self {
+            controller: Ssd1306Async::new(interface, PanelSize {}, DisplayRotation::Rotate0),
+            frame: DirtyFrame::new(),
+        }
+    }
+
+    /// Sets the display up to take data a page after the other, which the draw areas of the
+    /// flush rely on. The SH1106 is left to address a page at a time.
+    ///
+    /// The SSD1309 takes the init sequence of the SSD1306 as it is, so `ssd1309` builds the
+    /// same code and only makes the choice of the panel explicit.
+    pub async fn init(&mut self) -> Result<(), DisplayError> {
+        #[cfg(not(feature = "sh1106"))]
+        let addr_mode = AddrMode::Horizontal;
+        #[cfg(feature = "sh1106")]
+        let addr_mode = AddrMode::Page;
+        self.controller.init_with_addr_mode(addr_mode).await?;
+        self.frame.mark_all_dirty();
+        Ok(())
+    }
+
+    /// Shows a whole frame with the next flush, sending only the bytes that differ from what
+    /// is shown now.
+    pub fn show(&mut self, frame: &DisplayFrame) {
+        self.frame.copy_from(frame);
+    }
+
+    /// Sends the changed part of every page, each as a single write limited to its columns.
+    pub async fn flush(&mut self) -> Result<(), DisplayError> {
+        for (page, column, bytes) in self.frame.dirty_spans() {
+            let top = (page * 8) as u8;
+            let left = column as u8;
+            #[cfg(not(feature = "sh1106"))]
+            self.controller
+                .set_draw_area((left, top), (left + bytes.len() as u8, top + 8))
+                .await?;
+            #[cfg(feature = "sh1106")]
+            {
+                self.controller.set_row(top).await?;
+                self.controller
+                    .set_column(left + SH1106_COLUMN_OFFSET)
+                    .await?;
+            }
+            self.controller.draw(bytes).await?;
+        }
+        self.frame.mark_clean();
+        Ok(())
+    }
+
+    pub async fn set_invert(&mut self, invert: bool) -> Result<(), DisplayError> {
+        self.controller.set_invert(invert).await
+    }
+}
+
+impl OriginDimensions for Display {
+    fn size(&self) -> Size {
+        self.frame.size()
+    }
+}
+
+impl DrawTarget for Display {
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let Ok(()) = self.frame.draw_iter(pixels);
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let Ok(()) = self.frame.clear(color);
+        Ok(())
+    }
+}
@@ -1,33 +1,32 @@
-use embassy_rp::{
-    i2c::{self, I2c},
-    peripherals::I2C1,
-};
-use embedded_graphics::pixelcolor::BinaryColor;
-use ssd1306::{
-    mode::BufferedGraphicsModeAsync, prelude::I2CInterface, size::DisplaySize128x64, Ssd1306Async,
-};
+#[cfg(feature = "multiplayer")]
+use game_logic::session::Session;
+use pico_display::framebuffer::PackedFrame;
 
-pub type DisplayFrame = [BinaryColor; 8192];
+use crate::game::display;
 
-pub type Display = Ssd1306Async<
-    I2CInterface<I2c<'static, I2C1, i2c::Async>>,
-    DisplaySize128x64,
-    BufferedGraphicsModeAsync<DisplaySize128x64>,
->;
+/// A frame of the whole panel, e.g. eight pages of 128 bytes on a 128x64 one.
+pub type DisplayFrame = PackedFrame<{ display::WIDTH }, { display::PAGES }>;
 
+/// What the play task tells the others, kept small as it is queued in a channel. The last roll
+/// of a game that is over stays on the game layer of the renderer instead of being sent along.
 #[derive(PartialEq)]
 pub enum GameState {
     Playing,
-    Won(DisplayFrame),
-    Fish(DisplayFrame),
-    GameOver(DisplayFrame, i8),
+    Won(i16),
+    Lost(i16),
+    GameOver(i16),
+    #[cfg(feature = "multiplayer")]
+    TurnOver(Session),
+    /// The seed of the game that is over, to check against the hash shown before it.
+    #[cfg(feature = "provably-fair")]
+    SeedRevealed(u64),
 }
 
 impl GameState {
     pub fn is_final_state(&self) -> bool {
         matches!(
             self,
-            GameState::GameOver(_, _) | GameState::Won(_) | GameState::Fish(_)
+            GameState::GameOver(_) | GameState::Won(_) | GameState::Lost(_)
         )
     }
 }
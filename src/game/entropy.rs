@@ -0,0 +1,77 @@
+use embassy_rp::adc::{Adc, Blocking, Channel};
+use embassy_rp::clocks::RoscRng;
+use embassy_time::Instant;
+use game_logic::entropy::{EntropyPool, Source};
+use rand::rand_core::impls;
+use rand::RngCore;
+
+/// Reads of the floating pin per reseed, only the lowest bits of each are noise.
+const ADC_SAMPLES: usize = 16;
+
+/// The sources of randomness of the device: the random bit of the ring oscillator, the noise
+/// on a floating ADC pin, the timer since boot and the timing of every break of the beam, all
+/// mixed into one pool.
+pub struct Entropy {
+    pool: EntropyPool,
+    adc: Adc<'static, Blocking>,
+    floating_pin: Channel<'static>,
+}
+
+impl Entropy {
+    /// Takes the ADC along with a pin that is not connected to anything.
+    pub fn new(adc: Adc<'static, Blocking>, floating_pin: Channel<'static>) -> Self {
+        let mut entropy = Self {
+            pool: EntropyPool::new(),
+            adc,
+            floating_pin,
+        };
+        entropy.gather();
+        entropy
+    }
+
+    /// Mixes in how long the beam was broken along with when the break ended.
+    pub fn mix_beam_timing(&mut self, duration_in_mus: u64) {
+        self.pool.mix(Source::BeamTiming, duration_in_mus);
+        self.pool.mix(Source::Timer, Instant::now().as_ticks());
+    }
+
+    /// A seed for the next game, drawn after mixing in fresh samples of the hardware.
+    pub fn reseed(&mut self) -> u64 {
+        self.gather();
+        self.pool.next_u64()
+    }
+
+    pub fn samples(&self) -> u32 {
+        self.pool.samples()
+    }
+
+    fn gather(&mut self) {
+        self.pool.mix(Source::RingOscillator, ring_oscillator_u64());
+        for _ in 0..ADC_SAMPLES {
+            if let Ok(level) = self.adc.blocking_read(&mut self.floating_pin) {
+                self.pool.mix(Source::AdcNoise, level as u64);
+            }
+        }
+        self.pool.mix(Source::Timer, Instant::now().as_ticks());
+    }
+}
+
+/// Mixes a fresh sample of the ring oscillator into every draw.
+impl RngCore for Entropy {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.pool.mix(Source::RingOscillator, ring_oscillator_u64());
+        self.pool.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst)
+    }
+}
+
+fn ring_oscillator_u64() -> u64 {
+    (0..u64::BITS / u8::BITS).fold(0, |bits, _| bits << u8::BITS | RoscRng::next_u8() as u64)
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "pig")]
+use game_logic::pig::Action;
+
+#[cfg(feature = "manual-pick")]
+const SHORT_BREAK_IN_MUS: u64 = 400_000;
+const LONG_BREAK_IN_MUS: u64 = 1_000_000;
+
+/// The gestures to pick dice with the break beam sensor.
+#[cfg(feature = "manual-pick")]
+#[derive(PartialEq, Clone, Copy, defmt::Format)]
+pub enum PickInput {
+    /// A short break moves the cursor to the next die.
+    Next,
+    /// A medium break keeps or releases the die at the cursor.
+    Toggle,
+    /// A long break keeps the selected dice and rolls the rest.
+    Confirm,
+}
+
+#[cfg(feature = "manual-pick")]
+impl PickInput {
+    pub fn from_beam_duration(duration_in_mus: u64) -> Self {
+        if duration_in_mus < SHORT_BREAK_IN_MUS {
+            PickInput::Next
+        } else if duration_in_mus < LONG_BREAK_IN_MUS {
+            PickInput::Toggle
+        } else {
+            PickInput::Confirm
+        }
+    }
+}
+
+/// A long break banks the turn total, anything shorter rolls again.
+#[cfg(feature = "pig")]
+pub fn pig_action_from_beam_duration(duration_in_mus: u64) -> Action {
+    if duration_in_mus < LONG_BREAK_IN_MUS {
+        Action::Roll
+    } else {
+        Action::Bank
+    }
+}
+
+/// The gestures on the settings screen.
+#[cfg(feature = "themes")]
+#[derive(PartialEq, Clone, Copy, defmt::Format)]
+pub enum ThemeInput {
+    /// A short break shows the next theme.
+    Next,
+    /// A long break keeps the theme shown and starts the game.
+    Keep,
+}
+
+#[cfg(feature = "themes")]
+impl ThemeInput {
+    pub fn from_beam_duration(duration_in_mus: u64) -> Self {
+        if duration_in_mus < LONG_BREAK_IN_MUS {
+            ThemeInput::Next
+        } else {
+            ThemeInput::Keep
+        }
+    }
+}
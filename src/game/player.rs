@@ -1,52 +1,337 @@
-use alloc::string::{String, ToString};
-use alloc::vec::Vec;
-use defmt::info;
+#[cfg(any(
+    feature = "pig",
+    feature = "diagnostics",
+    feature = "provably-fair",
+    feature = "themes"
+))]
+use alloc::format;
+#[cfg(feature = "manual-pick")]
+use alloc::string::ToString;
+use defmt::{info, Display2Format};
 use embedded_graphics::pixelcolor::BinaryColor;
+#[cfg(any(feature = "pig", feature = "themes"))]
+use embedded_graphics::{prelude::*, primitives::Rectangle};
 
+#[cfg(feature = "provably-fair")]
+use game_logic::commitment::Commitment;
+use game_logic::dice_game::{DiceGame, Outcome};
+#[cfg(feature = "pig")]
+use game_logic::pig::{self, Action};
+#[cfg(feature = "diagnostics")]
+use game_logic::statistics::Fairness;
+#[cfg(feature = "manual-pick")]
+use game_logic::two_four_eighteen::pick::{PickError, Selection};
+#[cfg(feature = "manual-pick")]
 use game_logic::two_four_eighteen::{Game, NumberOfDice};
 use pico_display::aliases::Display as DisplayTrait;
+#[cfg(any(not(any(feature = "manual-pick", feature = "pig")), feature = "themes"))]
+use pico_display::dice::Dice;
+use pico_display::dice::RenderStyle;
+use pico_display::die::DieStyle;
+#[cfg(feature = "themes")]
+use pico_display::die::{Die, FaceValue};
+#[cfg(any(
+    feature = "manual-pick",
+    feature = "pig",
+    feature = "diagnostics",
+    feature = "provably-fair",
+    feature = "themes"
+))]
+use pico_display::messages;
 
 use crate::game::error::DrawError;
 
+#[cfg(feature = "manual-pick")]
+use crate::game::input::PickInput;
+
+/// The space between the text of the settings screen and the faces previewed below it.
+#[cfg(feature = "themes")]
+const THEME_PREVIEW_GAP: u32 = 2;
+
 #[derive(PartialEq)]
 pub enum GameResult {
-    Won,
-    Fish,
-    GameOver(i8),
+    Won(i16),
+    Lost(i16),
+    GameOver(i16),
     Playing,
+    #[cfg(feature = "manual-pick")]
+    Rejected(PickError),
 }
 
-pub fn play_and_draw<T>(display: &mut T, game: &mut Game) -> Result<GameResult, DrawError<T::Error>>
+impl GameResult {
+    pub fn final_score(&self) -> Option<i16> {
+        match self {
+            GameResult::Won(score) | GameResult::Lost(score) | GameResult::GameOver(score) => {
+                Some(*score)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+pub fn play_and_draw<T, G>(
+    display: &mut T,
+    game: &mut G,
+    theme: &DieStyle,
+) -> Result<GameResult, DrawError<T::Error>>
 where
     T: DisplayTrait,
+    G: DiceGame,
 {
     display.clear(BinaryColor::Off)?;
-    if game.dice_left > NumberOfDice::Zero {
+    if !game.is_over() {
         game.roll();
-        game.rolled.draw(display)?;
-        info!("current score: {}", game.score());
+        draw_roll(display, game, theme)?;
+        log_last_roll(game);
         Ok(GameResult::Playing)
     } else {
-        let mut picked: Vec<String> = game
-            .picked
-            .dice
-            .iter()
-            .map(|die| die.value.as_u8().to_string())
-            .collect();
-        picked.sort();
-        info!("picked: {}", picked.join(",").as_str());
-        let score = game.score();
-        info!("final score: {}", score);
-        game.picked.draw(display)?;
-        if game.has_fish() {
-            game.reset();
-            Ok(GameResult::Fish)
-        } else if game.has_won() {
-            game.reset();
-            Ok(GameResult::Won)
-        } else {
-            game.reset();
-            Ok(GameResult::GameOver(score))
+        finish_and_draw(display, game, theme)
+    }
+}
+
+#[cfg(feature = "manual-pick")]
+pub fn pick_and_draw<T>(
+    display: &mut T,
+    game: &mut Game,
+    selection: &mut Selection,
+    input: Option<PickInput>,
+    theme: &DieStyle,
+) -> Result<GameResult, DrawError<T::Error>>
+where
+    T: DisplayTrait,
+{
+    display.clear(BinaryColor::Off)?;
+    if let (true, Some(input)) = (game.is_awaiting_pick(), input) {
+        match input {
+            PickInput::Next => selection.next(),
+            PickInput::Toggle => selection.toggle(),
+            PickInput::Confirm => match game.keep(selection) {
+                Ok(()) => log_last_roll(game),
+                Err(err) => {
+                    messages::medium_sized_centered_message(err.to_string().as_str(), display)?;
+                    return Ok(GameResult::Rejected(err));
+                }
+            },
+        }
+    }
+    if !game.is_awaiting_pick() {
+        if game.dice_left == NumberOfDice::Zero {
+            return finish_and_draw(display, game, theme);
+        }
+        if let Err(err) = game.throw() {
+            return Ok(GameResult::Rejected(err));
         }
+        *selection = Selection::new(game.rolled.len());
+    }
+    game.rolled.draw_selection_themed(
+        display,
+        selection.cursor(),
+        |index| selection.is_selected(index),
+        *theme,
+    )?;
+    Ok(GameResult::Playing)
+}
+
+/// Plays the action, or the next move of the computer on its turn, and draws the die on the
+/// left with the scores next to it.
+#[cfg(feature = "pig")]
+pub fn pig_and_draw<T>(
+    display: &mut T,
+    game: &mut pig::Game,
+    action: Action,
+    theme: &DieStyle,
+) -> Result<GameResult, DrawError<T::Error>>
+where
+    T: DisplayTrait,
+{
+    display.clear(BinaryColor::Off)?;
+    game.play(action);
+    log_last_roll(game);
+    if game.is_over() {
+        return finish_and_draw(display, game, theme);
+    }
+
+    let size = display.size();
+    let die_area = Rectangle::new(Point::zero(), Size::new(size.height, size.height));
+    let style = RenderStyle::PLAIN.themed(*theme);
+    game.rolled
+        .draw_styled(&mut display.cropped(&die_area), |_| style)?;
+
+    let text_area = Rectangle::new(
+        Point::new(size.height as i32 + 4, 0),
+        Size::new(size.width.saturating_sub(size.height + 4), size.height),
+    );
+    let whose_turn = match game.player() {
+        pig::Player::Human => "Your turn",
+        pig::Player::Computer => "CPU's turn",
+    };
+    // whose turn it is comes first, the goal is the first to go on a short display
+    let scores = format!(
+        "{}\n\nYou:  {}\nCPU:  {}\nTurn: {}\nGoal: {}",
+        whose_turn,
+        game.score(pig::Player::Human),
+        game.score(pig::Player::Computer),
+        game.turn_total,
+        game.target_score,
+    );
+    messages::small_message(&scores, &mut display.cropped(&text_area))?;
+    Ok(GameResult::Playing)
+}
+
+/// Draws the outcome of the fairness self-test, the verdict first and then each test with its
+/// verdict and statistic.
+#[cfg(feature = "diagnostics")]
+pub fn draw_fairness<T>(display: &mut T, fairness: &Fairness) -> Result<(), DrawError<T::Error>>
+where
+    T: DisplayTrait,
+{
+    display.clear(BinaryColor::Off)?;
+    let verdict = |passes| if passes { "pass" } else { "FAIL" };
+    let dice = if fairness.passes() {
+        "fair"
+    } else {
+        "NOT fair"
+    };
+    let report = format!(
+        "Self-test: dice {dice}\n{} rolls\nUniform:     {}\n chi-square {:.2}\nIndependent: {}\n correlation {:.4}",
+        fairness.rolls,
+        verdict(fairness.is_uniform()),
+        fairness.chi_square,
+        verdict(fairness.is_independent()),
+        fairness.serial_correlation,
+    );
+    // a short display leaves out the rolls and has the statistics next to the verdicts
+    let report = if messages::small_message_fits(&report, display.size()) {
+        report
+    } else {
+        format!(
+            "Self-test: dice {dice}\nUniform:     {} {:.1}\nIndependent: {} {:.3}",
+            verdict(fairness.is_uniform()),
+            fairness.chi_square,
+            verdict(fairness.is_independent()),
+            fairness.serial_correlation,
+        )
+    };
+    messages::small_message(&report, display)?;
+    Ok(())
+}
+
+/// Draws the hash of the seed of the next game, to be noted down before its first roll.
+#[cfg(feature = "provably-fair")]
+pub fn draw_commitment<T>(
+    display: &mut T,
+    commitment: &Commitment,
+) -> Result<(), DrawError<T::Error>>
+where
+    T: DisplayTrait,
+{
+    display.clear(BinaryColor::Off)?;
+    let message = format!(
+        "Hash of the seed:\n{}\n\nThe seed is revealed\nonce the game is over.\n\nBreak the beam to roll.",
+        commitment.short()
+    );
+    let message = if messages::small_message_fits(&message, display.size()) {
+        message
+    } else {
+        format!(
+            "Hash: {}\nRevealed after the game.\nBreak the beam to roll.",
+            commitment.short()
+        )
+    };
+    messages::small_message(&message, display)?;
+    Ok(())
+}
+
+/// Draws the settings screen, the name of the theme above the six faces drawn in it.
+#[cfg(feature = "themes")]
+pub fn draw_theme<T>(
+    display: &mut T,
+    name: &str,
+    theme: &DieStyle,
+) -> Result<(), DrawError<T::Error>>
+where
+    T: DisplayTrait,
+{
+    display.clear(BinaryColor::Off)?;
+    let message = format!("Dice theme: {name}\nShort: next, long: keep");
+    let text = messages::small_message(&message, display)?;
+    let text_bottom = text
+        .and_then(|text| text.bottom_right())
+        .map_or(0, |corner| corner.y + 1);
+    let preview_top = text_bottom as u32 + THEME_PREVIEW_GAP;
+    let faces: Dice = [
+        FaceValue::One,
+        FaceValue::Two,
+        FaceValue::Three,
+        FaceValue::Four,
+        FaceValue::Five,
+        FaceValue::Six,
+    ]
+    .map(Die::new)
+    .to_vec()
+    .into();
+    let size = display.size();
+    let preview_area = Rectangle::new(
+        Point::new(0, preview_top as i32),
+        Size::new(size.width, size.height.saturating_sub(preview_top)),
+    );
+    let style = RenderStyle::PLAIN.themed(*theme);
+    faces.draw_styled(&mut display.cropped(&preview_area), |_| style)?;
+    Ok(())
+}
+
+/// Draws the dice kept in earlier rolls followed by the last roll. Kept dice are filled, the
+/// ones kept from the last roll underlined as well, and the dice going back into play dimmed.
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+fn draw_roll<T, G>(display: &mut T, game: &G, theme: &DieStyle) -> Result<(), T::Error>
+where
+    T: DisplayTrait,
+    G: DiceGame,
+{
+    let kept_before = game.kept_before_roll();
+    let mut table: Dice = kept_before.to_vec().into();
+    table.append(&mut game.rolled().clone());
+    let style = RenderStyle::PLAIN.themed(*theme);
+    table.draw_styled(display, |index| {
+        match index.checked_sub(kept_before.len()) {
+            None => style.inverted(),
+            Some(index) if game.is_kept(index) => style.inverted().underlined(),
+            Some(_) => style.dimmed(),
+        }
+    })
+}
+
+fn finish_and_draw<T, G>(
+    display: &mut T,
+    game: &mut G,
+    theme: &DieStyle,
+) -> Result<GameResult, DrawError<T::Error>>
+where
+    T: DisplayTrait,
+    G: DiceGame,
+{
+    info!("game log: {}", Display2Format(game.log()));
+    let style = RenderStyle::PLAIN.themed(*theme);
+    game.kept().draw_styled(display, |_| style)?;
+    let result = match game.outcome() {
+        Outcome::Won(score) => GameResult::Won(score),
+        Outcome::Lost(score) => GameResult::Lost(score),
+        Outcome::Over(score) => GameResult::GameOver(score),
+        Outcome::Playing => GameResult::Playing,
+    };
+    if let Some(score) = result.final_score() {
+        info!("final score: {}", score);
+    }
+    game.reset();
+    Ok(result)
+}
+
+fn log_last_roll<G>(game: &G)
+where
+    G: DiceGame,
+{
+    if let Some(event) = game.last_event() {
+        info!("{}", Display2Format(event));
     }
 }
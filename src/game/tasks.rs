@@ -1,47 +1,139 @@
-use defmt::info;
-use display_interface::DisplayError;
+use defmt::{debug, info};
 use embassy_executor::Spawner;
 use embassy_futures::select::{select, Either};
 use embassy_rp::gpio::{Input, Output};
 use embassy_rp::i2c::I2c;
 use embassy_rp::peripherals::I2C1;
-use embassy_sync::{blocking_mutex::raw::NoopRawMutex, channel::Channel, mutex::Mutex};
+use embassy_sync::{blocking_mutex::raw::NoopRawMutex, channel::Channel};
 use embassy_time::{Instant, Timer};
 use embedded_graphics::{draw_target::DrawTarget, pixelcolor::BinaryColor};
+#[cfg(any(not(feature = "manual-pick"), feature = "diagnostics"))]
 use rand::rngs::SmallRng;
+#[cfg(any(not(feature = "manual-pick"), feature = "diagnostics"))]
 use rand::SeedableRng;
-use ssd1306::{
-    mode::DisplayConfigAsync, rotation::DisplayRotation, size::DisplaySize128x64,
-    I2CDisplayInterface, Ssd1306Async,
-};
 use static_cell::StaticCell;
 use {defmt_rtt as _, panic_probe as _};
 
-use embedded_graphics_framebuf::FrameBuf;
+#[cfg(feature = "provably-fair")]
+use game_logic::commitment::Commitment;
+use game_logic::dice_game::DiceGame;
+#[cfg(feature = "farkle")]
+use game_logic::farkle;
+#[cfg(feature = "pig")]
+use game_logic::pig::{self, strategy::HoldAtN};
+#[cfg(feature = "multiplayer")]
+use game_logic::session::{RoundState, Session};
+#[cfg(feature = "diagnostics")]
+use game_logic::statistics;
+#[cfg(feature = "manual-pick")]
+use game_logic::two_four_eighteen::pick::Selection;
+#[cfg(not(any(feature = "farkle", feature = "pig")))]
+use game_logic::two_four_eighteen::rules::Rules;
+#[cfg(not(any(feature = "farkle", feature = "pig")))]
 use game_logic::two_four_eighteen::Game;
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+use pico_display::animation::{self, RollAnimation};
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+use pico_display::dice::Dice;
+use pico_display::die::DieStyle;
 use pico_display::messages;
 
 use crate::game::cache::FrameCache;
-use crate::game::entities::{Display, GameState};
+use crate::game::compositor::Renderer;
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+use crate::game::compositor::Tumble;
+use crate::game::display::Display;
+use crate::game::entities::GameState;
+use crate::game::entropy::Entropy;
+#[cfg(feature = "pig")]
+use crate::game::input;
+#[cfg(feature = "manual-pick")]
+use crate::game::input::PickInput;
+#[cfg(feature = "themes")]
+use crate::game::input::ThemeInput;
 use crate::game::player;
 use crate::game::player::GameResult;
 
 const ONE_SECOND_IN_MUS: u64 = 1000000;
+/// The house rules of the device, e.g. `Rules::SHIP_CAPTAIN_CREW` or `Rules::SEVEN_DICE`.
+#[cfg(not(any(feature = "farkle", feature = "pig")))]
+const RULES: Rules = Rules::TWO_FOUR_EIGHTEEN;
+#[cfg(feature = "pig")]
+const PIG_TARGET_SCORE: u16 = pig::DEFAULT_TARGET_SCORE;
+/// The computer banks once its turn total reaches this.
+#[cfg(feature = "pig")]
+const PIG_HOLD_AT: u16 = pig::strategy::DEFAULT_HOLD_AT;
+/// The pause between the moves of the computer, so that its rolls can be followed.
+#[cfg(feature = "pig")]
+const PIG_OPPONENT_MOVE_IN_MS: u64 = 800;
+#[cfg(feature = "multiplayer")]
+const PLAYER_NAMES: [&str; 2] = ["Player 1", "Player 2"];
+#[cfg(feature = "manual-pick")]
+const REJECTED_PICK_MESSAGE_IN_MS: u64 = 1500;
+/// How long the outcome of the fairness self-test is shown at boot.
+#[cfg(feature = "diagnostics")]
+const DIAGNOSTICS_IN_MS: u64 = 8000;
 
-type DisplayMutex = Mutex<NoopRawMutex, Display>;
-static DISPLAY: StaticCell<DisplayMutex> = StaticCell::new();
+/// The game played on the device. Any `DiceGame` can be played by breaking the beam, manual
+/// picking is specific to Two-Four-Eighteen and Pig tells short breaks from long ones.
+#[cfg(not(any(feature = "farkle", feature = "pig")))]
+type HostedGame = Game;
+#[cfg(feature = "farkle")]
+type HostedGame = farkle::Game;
+#[cfg(feature = "pig")]
+type HostedGame = pig::Game;
 
+#[cfg(not(any(feature = "farkle", feature = "pig")))]
+fn new_game(seed: u64) -> HostedGame {
+    Game::from_seed(seed, RULES)
+}
+
+#[cfg(feature = "farkle")]
+fn new_game(seed: u64) -> HostedGame {
+    farkle::Game::new(SmallRng::seed_from_u64(seed))
+}
+
+#[cfg(feature = "pig")]
+fn new_game(seed: u64) -> HostedGame {
+    pig::Game::with_opponent(
+        SmallRng::seed_from_u64(seed),
+        PIG_TARGET_SCORE,
+        HoldAtN(PIG_HOLD_AT),
+    )
+}
+
+static RENDERER: StaticCell<Renderer> = StaticCell::new();
+
+/// The duration of every break of the beam in microseconds, sent once the beam is whole again.
 type RollChannel = Channel<NoopRawMutex, u64, 4>;
 static ROLL_CHANNEL: StaticCell<RollChannel> = StaticCell::new();
 
-#[derive(PartialEq, Clone)]
-enum DisplayState {
-    Blink,
-    Solid,
+/// The frames taking turns on the display once a game is over.
+#[derive(PartialEq, Clone, Copy)]
+enum ShownFrame {
+    Message,
+    PickedDice,
+    /// The page of the scoreboard.
+    #[cfg(feature = "multiplayer")]
+    Scoreboard(usize),
+    #[cfg(feature = "provably-fair")]
+    Seed,
 }
 
-type DisplayStateChannel = Channel<NoopRawMutex, DisplayState, 4>;
-static DISPLAY_STATE_CHANNEL: StaticCell<DisplayStateChannel> = StaticCell::new();
+impl ShownFrame {
+    fn next(self) -> Self {
+        match self {
+            ShownFrame::Message => ShownFrame::PickedDice,
+            #[cfg(feature = "multiplayer")]
+            ShownFrame::PickedDice => ShownFrame::Scoreboard(0),
+            #[cfg(all(feature = "provably-fair", not(feature = "multiplayer")))]
+            ShownFrame::PickedDice => ShownFrame::Seed,
+            #[cfg(all(feature = "provably-fair", feature = "multiplayer"))]
+            ShownFrame::Scoreboard(_) => ShownFrame::Seed,
+            _ => ShownFrame::Message,
+        }
+    }
+}
 
 type GameStateChannel = Channel<NoopRawMutex, GameState, 4>;
 static GAME_STATE_CHANNEL: StaticCell<GameStateChannel> = StaticCell::new();
@@ -51,18 +143,27 @@ pub async fn spawn_tasks(
     sensor: Input<'static>,
     led: Output<'static>,
     i2c: I2c<'static, I2C1, embassy_rp::i2c::Async>,
+    #[cfg_attr(not(feature = "diagnostics"), allow(unused_mut))] mut entropy: Entropy,
 ) {
     let roll_channel = ROLL_CHANNEL.init(Channel::new());
     spawner
         .spawn(break_beam_roller_task(sensor, led, roll_channel))
         .unwrap();
 
-    let interface = I2CDisplayInterface::new(i2c);
-
-    let mut display = Ssd1306Async::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
-        .into_buffered_graphics_mode();
+    let mut display = Display::new(i2c);
     display.init().await.unwrap();
     display.clear(BinaryColor::Off).unwrap();
+    #[cfg(feature = "diagnostics")]
+    {
+        // the faces are rolled the way the games roll them, from a seed of the entropy pool
+        let mut small_rng = SmallRng::seed_from_u64(entropy.reseed());
+        let fairness = statistics::self_test(&mut small_rng, statistics::DEFAULT_ROLLS);
+        info!("Fairness self-test: {}", defmt::Display2Format(&fairness));
+        player::draw_fairness(&mut display, &fairness).unwrap();
+        display.flush().await.unwrap();
+        Timer::after_millis(DIAGNOSTICS_IN_MS).await;
+        display.clear(BinaryColor::Off).unwrap();
+    }
     messages::medium_sized_centered_message(
         "Break the beam for\n at least one second\n to start the game.",
         &mut display,
@@ -70,36 +171,37 @@ pub async fn spawn_tasks(
     .unwrap();
     display.flush().await.unwrap();
 
-    let display = DISPLAY.init(Mutex::new(display));
-    let display_state_channel = DISPLAY_STATE_CHANNEL.init(Channel::new());
+    // from here on only the renderer draws to the display
+    let renderer = RENDERER.init(Renderer::new());
+    spawner.spawn(renderer_task(display, renderer)).unwrap();
 
     let game_state_channel = GAME_STATE_CHANNEL.init(Channel::new());
     spawner
         .spawn(play_and_draw_task(
-            display,
+            renderer,
             roll_channel,
             game_state_channel,
+            entropy,
         ))
         .unwrap();
     spawner
-        .spawn(display_state_handler_task(display, display_state_channel))
-        .unwrap();
-    spawner
-        .spawn(display_animations_task(
-            display,
-            game_state_channel,
-            display_state_channel,
-        ))
+        .spawn(display_animations_task(renderer, game_state_channel))
         .unwrap();
 }
 
+/// Owns the display and composes every frame on it, see `Renderer`.
+#[embassy_executor::task]
+async fn renderer_task(mut display: Display, renderer: &'static Renderer) {
+    renderer.run(&mut display).await
+}
+
 #[embassy_executor::task]
 async fn break_beam_roller_task(
     mut sensor: Input<'static>,
     mut led: Output<'static>,
     roll_channel: &'static RollChannel,
 ) {
-    let mut seed: Option<u64> = None;
+    let mut started = false;
     let mut beam_broken_at: Option<Instant> = None;
 
     loop {
@@ -107,141 +209,409 @@ async fn break_beam_roller_task(
         if sensor.is_high() {
             led.set_high();
 
-            if let (Some(beam_broken_at), None) = (beam_broken_at, seed) {
+            if let Some(beam_broken_at) = beam_broken_at {
                 let duration = beam_broken_at.elapsed().as_micros();
-                if duration > ONE_SECOND_IN_MUS {
+                if started {
+                    // every break rolls the dice, or is a gesture to pick dice or bank with
                     roll_channel.send(duration).await;
-                    seed = Some(duration);
+                } else {
+                    if duration > ONE_SECOND_IN_MUS {
+                        roll_channel.send(duration).await;
+                        started = true;
+                    }
+                    info!("Beam broken for {} mus.", duration);
                 }
-                info!("Beam broken for {} mus.", duration);
             }
         } else {
             led.set_low();
-
-            if let Some(seed) = seed {
-                roll_channel.send(seed).await;
-            } else {
-                beam_broken_at = Some(Instant::now());
-            }
+            beam_broken_at = Some(Instant::now());
         }
         info!("Edge detected, level: {}", sensor.is_high());
     }
 }
 
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
 #[embassy_executor::task]
 async fn play_and_draw_task(
-    display: &'static DisplayMutex,
+    renderer: &'static Renderer,
     roll_channel: &'static RollChannel,
     game_state_channel: &'static GameStateChannel,
+    mut entropy: Entropy,
 ) {
-    let seed = roll_channel.receive().await;
-    let mut game = Game::new(SmallRng::seed_from_u64(seed));
-    let mut buffer = [BinaryColor::Off; 8192];
+    play_turns(
+        renderer,
+        roll_channel,
+        game_state_channel,
+        &mut entropy,
+        new_game,
+    )
+    .await;
+}
+
+/// Plays a roll per break of the beam, starting with the roll of the break that seeded the
+/// game. Embassy tasks cannot be generic, so the task above hands over how to start its game
+/// to this loop.
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+async fn play_turns<G>(
+    renderer: &Renderer,
+    roll_channel: &RollChannel,
+    game_state_channel: &GameStateChannel,
+    entropy: &mut Entropy,
+    new_game: fn(u64) -> G,
+) where
+    G: DiceGame,
+{
+    let mut beam_duration = roll_channel.receive().await;
+    let mut seed = first_seed(entropy, beam_duration);
+    #[cfg(feature = "themes")]
+    let theme = pick_theme(renderer, roll_channel, entropy).await;
+    #[cfg(not(feature = "themes"))]
+    let theme = DieStyle::CLASSIC;
+    #[cfg(feature = "provably-fair")]
+    {
+        beam_duration = commit_to_seed(renderer, roll_channel, entropy, seed).await;
+    }
+    let mut game = new_game(seed);
+
+    #[cfg(feature = "multiplayer")]
+    let mut session = Session::new(&PLAYER_NAMES).unwrap();
+    #[cfg(feature = "multiplayer")]
+    show_whose_turn(renderer, &session).await;
 
     info!("Game starts!");
     loop {
-        let game_result = {
-            let mut framebuffer = FrameBuf::new(&mut buffer, 128, 64);
-            let game_result = player::play_and_draw(&mut framebuffer, &mut game).unwrap();
-
-            let mut display = display.lock().await;
-            display.draw_iter(&framebuffer).unwrap();
-            display.flush().await.unwrap();
-            game_result
+        let game_result = renderer
+            .draw_game(|frame| player::play_and_draw(frame, &mut game, &theme))
+            .await
+            .unwrap();
+        if game_result == GameResult::Playing {
+            tumble(renderer, game.rolled(), &theme, beam_duration, entropy).await;
+        }
+        renderer.show_game().await;
+        let turn_over = game_result.final_score();
+        send_game_result(game_state_channel, game_result).await;
+        #[cfg(feature = "multiplayer")]
+        if let Some(score) = turn_over {
+            record_turn(&mut session, score, game_state_channel).await;
+            show_whose_turn(renderer, &session).await;
+        }
+        if turn_over.is_some() {
+            info!("Seed of the game: {}", seed);
+            #[cfg(feature = "provably-fair")]
+            game_state_channel.send(GameState::SeedRevealed(seed)).await;
+            seed = entropy.reseed();
+            game.reseed(seed);
+        }
+        beam_duration = roll_channel.receive().await;
+        entropy.mix_beam_timing(beam_duration);
+        #[cfg(feature = "provably-fair")]
+        if turn_over.is_some() {
+            game_state_channel.send(GameState::Playing).await;
+            beam_duration = commit_to_seed(renderer, roll_channel, entropy, seed).await;
+        }
+    }
+}
+
+#[cfg(feature = "manual-pick")]
+#[embassy_executor::task]
+async fn play_and_draw_task(
+    renderer: &'static Renderer,
+    roll_channel: &'static RollChannel,
+    game_state_channel: &'static GameStateChannel,
+    mut entropy: Entropy,
+) {
+    let seed = first_seed(&mut entropy, roll_channel.receive().await);
+    #[cfg(feature = "themes")]
+    let theme = pick_theme(renderer, roll_channel, &mut entropy).await;
+    #[cfg(not(feature = "themes"))]
+    let theme = DieStyle::CLASSIC;
+    let mut game = new_game(seed);
+    #[cfg(feature = "multiplayer")]
+    let mut session = Session::new(&PLAYER_NAMES).unwrap();
+    #[cfg(feature = "multiplayer")]
+    show_whose_turn(renderer, &session).await;
+    let mut selection = Selection::default();
+    let mut input = None;
+
+    info!("Game starts!");
+    loop {
+        let game_result = renderer
+            .draw_game(|frame| {
+                player::pick_and_draw(frame, &mut game, &mut selection, input, &theme)
+            })
+            .await
+            .unwrap();
+        renderer.show_game().await;
+        input = if let GameResult::Rejected(err) = game_result {
+            info!("Pick rejected: {}", defmt::Display2Format(&err));
+            // show the reason for a while before going back to the dice
+            Timer::after_millis(REJECTED_PICK_MESSAGE_IN_MS).await;
+            None
+        } else {
+            let turn_over = game_result.final_score();
+            send_game_result(game_state_channel, game_result).await;
+            #[cfg(feature = "multiplayer")]
+            if let Some(score) = turn_over {
+                record_turn(&mut session, score, game_state_channel).await;
+                show_whose_turn(renderer, &session).await;
+            }
+            if turn_over.is_some() {
+                game.reseed(entropy.reseed());
+            }
+            let duration = roll_channel.receive().await;
+            entropy.mix_beam_timing(duration);
+            let input = PickInput::from_beam_duration(duration);
+            info!("Pick input: {}", input);
+            Some(input)
         };
-        match game_result {
-            GameResult::GameOver(score) => {
-                game_state_channel
-                    .send(GameState::GameOver(buffer, score))
-                    .await;
+    }
+}
+
+/// Plays a short break as a roll and a long break as banking the turn total, followed by the
+/// moves of the computer once the turn is over.
+#[cfg(feature = "pig")]
+#[embassy_executor::task]
+async fn play_and_draw_task(
+    renderer: &'static Renderer,
+    roll_channel: &'static RollChannel,
+    game_state_channel: &'static GameStateChannel,
+    mut entropy: Entropy,
+) {
+    let seed = first_seed(&mut entropy, roll_channel.receive().await);
+    #[cfg(feature = "themes")]
+    let theme = pick_theme(renderer, roll_channel, &mut entropy).await;
+    #[cfg(not(feature = "themes"))]
+    let theme = DieStyle::CLASSIC;
+    let mut game = new_game(seed);
+    let mut action = pig::Action::Roll;
+
+    info!("Game starts!");
+    loop {
+        loop {
+            let game_result = renderer
+                .draw_game(|frame| player::pig_and_draw(frame, &mut game, action, &theme))
+                .await
+                .unwrap();
+            renderer.show_game().await;
+            let game_over = game_result.final_score().is_some();
+            send_game_result(game_state_channel, game_result).await;
+            if game_over {
+                game.reseed(entropy.reseed());
             }
-            GameResult::Won => {
-                game_state_channel.send(GameState::Won(buffer)).await;
+            if game.player() != pig::Player::Computer {
+                break;
             }
-            GameResult::Fish => {
-                game_state_channel.send(GameState::Fish(buffer)).await;
+            Timer::after_millis(PIG_OPPONENT_MOVE_IN_MS).await;
+        }
+        let duration = roll_channel.receive().await;
+        entropy.mix_beam_timing(duration);
+        action = input::pig_action_from_beam_duration(duration);
+        info!("Pig action: {}", defmt::Debug2Format(&action));
+    }
+}
+
+/// Mixes the timing of the break of the beam that starts the first game into its seed, every
+/// later game is reseeded from the entropy once the previous one is over.
+fn first_seed(entropy: &mut Entropy, beam_duration: u64) -> u64 {
+    entropy.mix_beam_timing(beam_duration);
+    let seed = entropy.reseed();
+    info!("Seeded from {} samples", entropy.samples());
+    seed
+}
+
+/// Has the rolled dice tumble before they land, for longer the longer the beam was broken.
+/// The renderer shows the game layer again once they landed.
+#[cfg(not(any(feature = "manual-pick", feature = "pig")))]
+async fn tumble(
+    renderer: &Renderer,
+    rolled: &Dice,
+    theme: &DieStyle,
+    beam_duration: u64,
+    entropy: &mut Entropy,
+) {
+    // the tumbling faces are only for show, they need not all come from the hardware
+    let mut small_rng = SmallRng::from_rng(entropy);
+    let frames = animation::frames_for_beam_duration(beam_duration);
+    let animation = RollAnimation::new(rolled, frames, &mut small_rng).themed(*theme);
+    renderer
+        .tumble(Tumble {
+            animation,
+            small_rng,
+        })
+        .await;
+}
+
+/// Shows the settings screen until a long break keeps the theme shown and starts the game,
+/// short breaks move on to the next one.
+#[cfg(feature = "themes")]
+async fn pick_theme(
+    renderer: &Renderer,
+    roll_channel: &RollChannel,
+    entropy: &mut Entropy,
+) -> DieStyle {
+    let mut index = 0;
+    loop {
+        let (name, theme) = DieStyle::THEMES[index];
+        renderer
+            .draw_game(|frame| player::draw_theme(frame, name, &theme))
+            .await
+            .unwrap();
+        renderer.show_game().await;
+        let beam_duration = roll_channel.receive().await;
+        entropy.mix_beam_timing(beam_duration);
+        match ThemeInput::from_beam_duration(beam_duration) {
+            ThemeInput::Next => index = (index + 1) % DieStyle::THEMES.len(),
+            ThemeInput::Keep => {
+                info!("Dice theme: {}", name);
+                return theme;
             }
-            GameResult::Playing => {
-                game_state_channel.send(GameState::Playing).await;
+        }
+    }
+}
+
+/// Shows the hash of the seed and waits for the break of the beam that rolls, so that the
+/// seed revealed after the game can be checked against it. Returns the duration of that break.
+#[cfg(feature = "provably-fair")]
+async fn commit_to_seed(
+    renderer: &Renderer,
+    roll_channel: &RollChannel,
+    entropy: &mut Entropy,
+    seed: u64,
+) -> u64 {
+    let commitment = Commitment::to_seed(seed);
+    info!("Hash of the seed: {}", defmt::Display2Format(&commitment));
+    renderer
+        .draw_game(|frame| player::draw_commitment(frame, &commitment))
+        .await
+        .unwrap();
+    renderer.show_game().await;
+    let beam_duration = roll_channel.receive().await;
+    entropy.mix_beam_timing(beam_duration);
+    beam_duration
+}
+
+#[cfg(feature = "multiplayer")]
+async fn record_turn(session: &mut Session, score: i16, game_state_channel: &GameStateChannel) {
+    match session.record(score) {
+        RoundState::Won(_) => {
+            if let Some(winner) = session.winner() {
+                info!("{} wins round {}", winner.name, session.round());
             }
         }
-        roll_channel.receive().await;
+        RoundState::RollOff => info!("Round {} goes to a roll-off", session.round()),
+        RoundState::Playing => {}
     }
+    game_state_channel.send(GameState::TurnOver(*session)).await;
 }
 
+/// Names the player whose turn it is in the status bar.
+#[cfg(feature = "multiplayer")]
+async fn show_whose_turn(renderer: &Renderer, session: &Session) {
+    let name = session
+        .current_player()
+        .map(|index| session.players()[index].name);
+    renderer.set_status(name).await;
+}
+
+/// Sends the state of the game, timing the send as it used to queue the picked dice along.
+async fn send_game_result(game_state_channel: &GameStateChannel, game_result: GameResult) {
+    let started_at = Instant::now();
+    match game_result {
+        GameResult::GameOver(score) => {
+            game_state_channel.send(GameState::GameOver(score)).await;
+        }
+        GameResult::Won(score) => {
+            game_state_channel.send(GameState::Won(score)).await;
+        }
+        GameResult::Lost(score) => {
+            game_state_channel.send(GameState::Lost(score)).await;
+        }
+        GameResult::Playing => {
+            game_state_channel.send(GameState::Playing).await;
+        }
+        #[cfg(feature = "manual-pick")]
+        GameResult::Rejected(_) => return,
+    }
+    debug!(
+        "Game state sent in {} mus",
+        started_at.elapsed().as_micros()
+    );
+}
+
+/// Takes turns showing the message, the picked dice and whatever else there is to see about
+/// a game that is over, while the display blinks.
 #[embassy_executor::task]
 async fn display_animations_task(
-    display: &'static DisplayMutex,
+    renderer: &'static Renderer,
     game_state_channel: &'static GameStateChannel,
-    display_state_channel: &'static DisplayStateChannel,
 ) {
     let mut game_state = GameState::Playing;
-    let mut show_message = true;
+    let mut shown_frame = ShownFrame::Message;
 
-    let mut frame_cache = FrameCache::init().unwrap();
+    let mut frame_cache = FrameCache::init(HostedGame::LOST_MESSAGE).unwrap();
 
     loop {
         match select(Timer::after_millis(2000), game_state_channel.receive()).await {
             Either::First(_) => {
                 if game_state.is_final_state() {
-                    let mut display = display.lock().await;
-
-                    if show_message {
-                        frame_cache.draw_message(&mut display, &game_state).unwrap();
-                    } else {
-                        frame_cache.draw_picked_dice(&mut display).unwrap();
+                    match shown_frame {
+                        ShownFrame::Message => {
+                            if let Some(message) = frame_cache.message(&game_state) {
+                                renderer.show_overlay(message).await;
+                            }
+                        }
+                        // the game layer still shows the last roll
+                        ShownFrame::PickedDice => renderer.hide_overlay().await,
+                        #[cfg(feature = "multiplayer")]
+                        ShownFrame::Scoreboard(page) => {
+                            if let Some(scoreboard) = frame_cache.scoreboard(page).unwrap() {
+                                renderer.show_overlay(scoreboard).await;
+                            }
+                        }
+                        #[cfg(feature = "provably-fair")]
+                        ShownFrame::Seed => renderer.show_overlay(frame_cache.seed()).await,
                     }
-                    display.flush().await.unwrap();
-                    show_message = !show_message;
+                    shown_frame = match shown_frame {
+                        #[cfg(feature = "multiplayer")]
+                        ShownFrame::Scoreboard(page)
+                            if page + 1 < frame_cache.scoreboard_pages() =>
+                        {
+                            ShownFrame::Scoreboard(page + 1)
+                        }
+                        shown_frame => shown_frame.next(),
+                    };
                 }
             }
             Either::Second(state) => match state {
-                GameState::Won(frame) | GameState::Fish(frame) => {
-                    display_state_channel.send(DisplayState::Blink).await;
+                GameState::Won(score) => {
+                    renderer.set_blinking(true).await;
                     game_state = state;
-                    frame_cache.replace_picked_dice_frame(frame);
+                    frame_cache.update_you_won_frame(score).unwrap();
                 }
-                GameState::GameOver(frame, score) => {
-                    display_state_channel.send(DisplayState::Blink).await;
+                GameState::Lost(_) => {
+                    renderer.set_blinking(true).await;
+                    game_state = state;
+                }
+                GameState::GameOver(score) => {
+                    renderer.set_blinking(true).await;
                     game_state = state;
-                    frame_cache.replace_picked_dice_frame(frame);
                     frame_cache.update_score_frame(score).unwrap();
                 }
+                #[cfg(feature = "multiplayer")]
+                GameState::TurnOver(session) => {
+                    frame_cache.update_scoreboard_pages(&session);
+                }
+                #[cfg(feature = "provably-fair")]
+                GameState::SeedRevealed(seed) => {
+                    frame_cache.update_seed_frame(seed).unwrap();
+                }
                 state => {
-                    display_state_channel.send(DisplayState::Solid).await;
+                    renderer.set_blinking(false).await;
+                    renderer.hide_overlay().await;
                     game_state = state;
-                    show_message = true;
+                    shown_frame = ShownFrame::Message;
                 }
             },
         }
     }
 }
-
-#[embassy_executor::task]
-async fn display_state_handler_task(
-    display: &'static DisplayMutex,
-    display_state_channel: &'static DisplayStateChannel,
-) {
-    let mut invert_display = false;
-    let mut display_state = DisplayState::Solid;
-
-    loop {
-        match select(Timer::after_millis(1000), display_state_channel.receive()).await {
-            Either::First(_) => {
-                if display_state == DisplayState::Blink {
-                    invert_display = !invert_display;
-                    set_invert_display(display, invert_display).await.unwrap();
-                }
-            }
-            Either::Second(state) => {
-                display_state = state;
-                invert_display = display_state != DisplayState::Solid;
-                set_invert_display(display, invert_display).await.unwrap();
-            }
-        }
-    }
-}
-
-async fn set_invert_display(display: &DisplayMutex, invert: bool) -> Result<(), DisplayError> {
-    display.lock().await.set_invert(invert).await
-}
@@ -3,8 +3,24 @@
 
 extern crate alloc;
 
+#[cfg(all(feature = "farkle", feature = "manual-pick"))]
+compile_error!("manual picking is only available for Two-Four-Eighteen");
+#[cfg(all(
+    feature = "pig",
+    any(feature = "farkle", feature = "manual-pick", feature = "multiplayer")
+))]
+compile_error!("Pig is played on its own against the computer");
+#[cfg(all(
+    feature = "provably-fair",
+    any(feature = "manual-pick", feature = "pig")
+))]
+compile_error!("only games played by their strategy can be replayed from the seed");
+#[cfg(all(feature = "ssd1309", any(feature = "panel-128x32", feature = "sh1106")))]
+compile_error!("the SSD1309 is a 128x64 panel of its own controller");
+
 use embassy_executor::Spawner;
 use embassy_rp::{
+    adc::{self, Adc},
     bind_interrupts,
     gpio::{Input, Level, Output, Pull},
     i2c::{self, Config as I2cConfig, I2c},
@@ -15,8 +31,13 @@ use {defmt_rtt as _, panic_probe as _};
 
 mod game {
     pub mod cache;
+    pub mod compositor;
+    pub mod display;
     pub mod entities;
+    pub mod entropy;
     pub mod error;
+    #[cfg(any(feature = "manual-pick", feature = "pig", feature = "themes"))]
+    pub mod input;
     pub mod player;
     pub mod tasks;
 }
@@ -58,8 +79,12 @@ async fn main(spawner: Spawner) {
     let led = Output::new(p.PIN_25, Level::Low);
     let sensor = Input::new(p.PIN_21, Pull::Up);
     let i2c = I2c::new_async(p.I2C1, p.PIN_7, p.PIN_6, Irqs, config);
+    // GPIO28 is left unconnected, its readings are noise to seed the dice with
+    let adc = Adc::new_blocking(p.ADC, adc::Config::default());
+    let floating_pin = adc::Channel::new_pin(p.PIN_28, Pull::None);
+    let entropy = game::entropy::Entropy::new(adc, floating_pin);
 
-    game::tasks::spawn_tasks(&spawner, sensor, led, i2c).await;
+    game::tasks::spawn_tasks(&spawner, sensor, led, i2c, entropy).await;
 
     #[cfg(feature = "temperature")]
     {
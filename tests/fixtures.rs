@@ -0,0 +1,23 @@
+//! Helpers shared by the tests of the games.
+use pico_display::dice::Dice;
+use pico_display::die::{Die, FaceValue};
+
+/// Dice showing the given faces in order. Panics on a value no die shows, so that a typo in a
+/// test case does not quietly turn into another face.
+pub fn dice(values: &[u8]) -> Dice {
+    let face_value = |value| match value {
+        1 => FaceValue::One,
+        2 => FaceValue::Two,
+        3 => FaceValue::Three,
+        4 => FaceValue::Four,
+        5 => FaceValue::Five,
+        6 => FaceValue::Six,
+        _ => panic!("no die shows {value}"),
+    };
+    Dice::from(
+        values
+            .iter()
+            .map(|&value| Die::new(face_value(value)))
+            .collect::<Vec<_>>(),
+    )
+}
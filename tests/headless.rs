@@ -0,0 +1,202 @@
+//! Draws into memory instead of a simulator window, so that the screens can be tested
+//! unattended. Frames are compared against the golden images in `golden/`, run with
+//! `UPDATE_GOLDEN=1`, e.g. `cargo make update-golden`, to write them after a deliberate change.
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::PathBuf;
+
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+
+/// Set to write the rendered frames as the new golden images.
+pub const UPDATE_GOLDEN: &str = "UPDATE_GOLDEN";
+
+/// A monochrome image in memory, read from and written to binary PBM files.
+pub struct Frame {
+    pub size: Size,
+    pub pixels: Vec<bool>,
+    /// How many pixels were drawn outside of the frame, and left out.
+    pub outside: usize,
+}
+
+impl Frame {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            size: Size::new(width, height),
+            pixels: vec![false; (width * height) as usize],
+            outside: 0,
+        }
+    }
+
+    /// A blank frame of the given size with whatever `draw` draws into it.
+    pub fn render<F>(width: u32, height: u32, draw: F) -> Self
+    where
+        F: FnOnce(&mut Frame) -> Result<(), core::convert::Infallible>,
+    {
+        let mut frame = Frame::new(width, height);
+        draw(&mut frame).unwrap();
+        frame
+    }
+
+    pub fn is_on(&self, point: Point) -> bool {
+        self.bounding_box().contains(point) && self.pixels[self.index(point)]
+    }
+
+    pub fn lit_pixels(&self) -> usize {
+        self.pixels.iter().filter(|&&on| on).count()
+    }
+
+    pub fn to_pbm(&self) -> Vec<u8> {
+        let mut pbm = format!("P4\n{} {}\n", self.size.width, self.size.height).into_bytes();
+        for row in self.pixels.chunks(self.size.width as usize) {
+            for byte in row.chunks(8) {
+                let bits = byte
+                    .iter()
+                    .enumerate()
+                    .fold(0u8, |bits, (bit, &on)| bits | ((on as u8) << (7 - bit)));
+                pbm.push(bits);
+            }
+        }
+        pbm
+    }
+
+    pub fn from_pbm(pbm: &[u8]) -> Self {
+        let mut fields = pbm.splitn(4, |byte| byte.is_ascii_whitespace());
+        assert_eq!(fields.next(), Some(&b"P4"[..]), "not a binary PBM");
+        let mut number = || -> u32 {
+            std::str::from_utf8(fields.next().unwrap())
+                .unwrap()
+                .parse()
+                .unwrap()
+        };
+        let (width, height) = (number(), number());
+        let data = fields.next().unwrap();
+        let bytes_per_row = width.div_ceil(8) as usize;
+
+        let mut frame = Frame::new(width, height);
+        for y in 0..height as usize {
+            for x in 0..width as usize {
+                let byte = data[y * bytes_per_row + x / 8];
+                frame.pixels[y * width as usize + x] = byte & (0x80 >> (x % 8)) != 0;
+            }
+        }
+        frame
+    }
+
+    pub fn differing_pixels(&self, other: &Frame) -> usize {
+        self.pixels
+            .iter()
+            .zip(&other.pixels)
+            .filter(|(a, b)| a != b)
+            .count()
+    }
+
+    /// A binary PPM of both frames on top of each other: pixels lit in both are black, the
+    /// ones only the golden image has red and the ones only this frame has green.
+    pub fn diff_ppm(&self, golden: &Frame) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.size.width, self.size.height).into_bytes();
+        for (&on, &golden_on) in self.pixels.iter().zip(&golden.pixels) {
+            let rgb = match (golden_on, on) {
+                (true, true) => [0, 0, 0],
+                (true, false) => [255, 0, 0],
+                (false, true) => [0, 160, 0],
+                (false, false) => [255, 255, 255],
+            };
+            ppm.extend(rgb);
+        }
+        ppm
+    }
+
+    fn index(&self, point: Point) -> usize {
+        (point.y as u32 * self.size.width + point.x as u32) as usize
+    }
+}
+
+impl PartialEq for Frame {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.pixels == other.pixels
+    }
+}
+
+impl OriginDimensions for Frame {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl DrawTarget for Frame {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if self.bounding_box().contains(point) {
+                let index = self.index(point);
+                self.pixels[index] = color.is_on();
+            } else {
+                self.outside += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn golden_path(group: &str, name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("golden")
+        .join(group)
+        .join(format!("{name}.pbm"))
+}
+
+fn mismatch_path(group: &str, name: &str, extension: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("golden")
+        .join(group)
+        .join(format!("{name}.{extension}"))
+}
+
+/// Compares the frame with `golden/<group>/<name>.pbm`. On a mismatch the frame and the diff
+/// are written next to each other into the target directory, see `Frame::diff_ppm`.
+pub fn assert_golden(group: &str, name: &str, frame: &Frame) {
+    let path = golden_path(group, name);
+    if std::env::var_os(UPDATE_GOLDEN).is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, frame.to_pbm()).unwrap();
+        return;
+    }
+    let golden = fs::read(&path).unwrap_or_else(|_| {
+        panic!(
+            "no golden image at {}, run with {UPDATE_GOLDEN}=1 to write it",
+            path.display()
+        )
+    });
+    let golden = Frame::from_pbm(&golden);
+    if golden == *frame {
+        return;
+    }
+
+    let actual = mismatch_path(group, name, "pbm");
+    fs::create_dir_all(actual.parent().unwrap()).unwrap();
+    fs::write(&actual, frame.to_pbm()).unwrap();
+    if golden.size != frame.size {
+        panic!(
+            "{group}/{name} is {}x{} instead of {}x{}, see {}",
+            frame.size.width,
+            frame.size.height,
+            golden.size.width,
+            golden.size.height,
+            actual.display()
+        );
+    }
+    let diff = mismatch_path(group, name, "diff.ppm");
+    fs::write(&diff, frame.diff_ppm(&golden)).unwrap();
+    panic!(
+        "{group}/{name} differs from the golden image in {} pixels, see {}",
+        frame.differing_pixels(&golden),
+        diff.display()
+    );
+}
@@ -0,0 +1,27 @@
+//! The mock display of the tests that check which pixels the dice light up, rather than how
+//! they look as a whole.
+#![allow(dead_code)]
+
+use embedded_graphics::mock_display::MockDisplay;
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+
+/// A 64x64 display that panics when drawn outside of it, so that drawing into it checks that
+/// nothing ends up off the display.
+pub fn display() -> MockDisplay<BinaryColor> {
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    display
+}
+
+pub fn lit_points(display: &MockDisplay<BinaryColor>) -> Vec<Point> {
+    display
+        .bounding_box()
+        .points()
+        .filter(|&point| display.get_pixel(point) == Some(BinaryColor::On))
+        .collect()
+}
+
+pub fn lit_pixels(display: &MockDisplay<BinaryColor>) -> usize {
+    lit_points(display).len()
+}
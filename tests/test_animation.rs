@@ -0,0 +1,149 @@
+mod mock;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use pico_display::animation::{
+        frames_for_beam_duration, RollAnimation, MAX_FRAMES, MIN_FRAMES,
+    };
+    use pico_display::dice::Dice;
+    use pico_display::polyhedral::{PolyhedralDie, Sides};
+
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::mock::display;
+
+    fn rolled(number_of_dice: u32, rng: &mut SmallRng) -> Dice {
+        Dice::roll(|| rng.random(), number_of_dice)
+    }
+
+    fn values(dice: &Dice) -> Vec<u8> {
+        dice.dice.iter().map(|die| die.value.as_u8()).collect()
+    }
+
+    /// Plays the animation to the end and returns the faces of every frame.
+    fn play(animation: &mut RollAnimation, rng: &mut SmallRng) -> Vec<Vec<u8>> {
+        let mut frames = vec![values(animation.shown())];
+        while animation.advance(rng) {
+            frames.push(values(animation.shown()));
+        }
+        frames
+    }
+
+    #[rstest]
+    #[case(0, MIN_FRAMES)]
+    #[case(49_999, MIN_FRAMES)]
+    #[case(50_000, MIN_FRAMES + 1)]
+    #[case(500_000, MIN_FRAMES + 10)]
+    #[case(60_000_000, MAX_FRAMES)]
+    #[case(u64::MAX, MAX_FRAMES)]
+    fn longer_breaks_tumble_longer(#[case] beam_duration_in_mus: u64, #[case] frames: u32) {
+        assert_eq!(frames_for_beam_duration(beam_duration_in_mus), frames);
+    }
+
+    #[rstest]
+    #[case(1, MIN_FRAMES)]
+    #[case(6, MIN_FRAMES)]
+    #[case(6, 20)]
+    #[case(6, MAX_FRAMES)]
+    fn lands_on_the_rolled_dice(#[case] number_of_dice: u32, #[case] frames: u32) {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let dice = rolled(number_of_dice, &mut rng);
+        let mut animation = RollAnimation::new(&dice, frames, &mut rng);
+
+        let played = play(&mut animation, &mut rng);
+        assert_eq!(played.len(), frames as usize + 1);
+        assert_eq!(played.last(), Some(&values(&dice)));
+        assert!(animation.is_landed());
+        assert_eq!(animation.frame(), frames);
+        assert!(!animation.advance(&mut rng));
+    }
+
+    #[test_log::test]
+    fn landed_dice_look_like_the_rolled_dice() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let dice = rolled(4, &mut rng);
+        let mut animation = RollAnimation::new(&dice, MIN_FRAMES, &mut rng);
+        while animation.advance(&mut rng) {}
+
+        let mut landed = display();
+        animation.draw(&mut landed).unwrap();
+        let mut expected = display();
+        dice.draw(&mut expected).unwrap();
+        landed.assert_eq(&expected);
+    }
+
+    #[test_log::test]
+    fn no_frames_show_the_dice_right_away() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let dice = rolled(3, &mut rng);
+        let mut animation = RollAnimation::new(&dice, 0, &mut rng);
+        assert!(animation.is_landed());
+        assert_eq!(values(animation.shown()), values(&dice));
+        assert!(!animation.advance(&mut rng));
+    }
+
+    #[test_log::test]
+    fn tumbling_dice_show_other_faces() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let dice = rolled(6, &mut rng);
+        let mut animation = RollAnimation::new(&dice, MAX_FRAMES, &mut rng);
+        let played = play(&mut animation, &mut rng);
+        let tumbling = &played[..played.len() - 1];
+        assert!(tumbling.iter().any(|faces| *faces != values(&dice)));
+    }
+
+    #[test_log::test]
+    fn dice_slow_down_before_landing() {
+        let mut rng = SmallRng::seed_from_u64(5);
+        let dice = rolled(6, &mut rng);
+        let mut animation = RollAnimation::new(&dice, MAX_FRAMES, &mut rng);
+        let played = play(&mut animation, &mut rng);
+        let tumbling = &played[..played.len() - 1];
+        let changes =
+            |frames: &[Vec<u8>]| frames.windows(2).filter(|pair| pair[0] != pair[1]).count();
+        let half = tumbling.len() / 2;
+        assert!(changes(&tumbling[..half]) > changes(&tumbling[half..]) + 5);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(5)]
+    #[case(6)]
+    #[case(12)]
+    fn every_frame_stays_inside_the_display(#[case] number_of_dice: u32) {
+        let mut rng = SmallRng::seed_from_u64(number_of_dice as u64);
+        let dice = rolled(number_of_dice, &mut rng);
+        let mut animation = RollAnimation::new(&dice, MAX_FRAMES, &mut rng);
+        loop {
+            animation.draw(&mut display()).unwrap();
+            if !animation.advance(&mut rng) {
+                break;
+            }
+        }
+    }
+
+    #[test_log::test]
+    fn tumbles_polyhedral_dice_on_their_own_faces() {
+        let mut rng = SmallRng::seed_from_u64(11);
+        let dice = Dice::roll_sides(Sides::D20, 3, &mut rng);
+        let mut animation = RollAnimation::new(&dice, MAX_FRAMES, &mut rng);
+        loop {
+            animation.draw(&mut display()).unwrap();
+            assert!(animation
+                .shown()
+                .dice
+                .iter()
+                .all(
+                    |die: &PolyhedralDie| die.sides == Sides::D20 && (1..=20).contains(&die.value)
+                ));
+            if !animation.advance(&mut rng) {
+                break;
+            }
+        }
+        assert!(animation.shown() == &dice);
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use game_logic::commitment::sha256::sha256;
+    use game_logic::commitment::{self, Commitment, VerifyError, SHORT_HASH_BYTES};
+    use game_logic::dice_game::DiceGame;
+    use game_logic::farkle;
+    use game_logic::two_four_eighteen::rules::Rules;
+    use game_logic::two_four_eighteen::Game;
+
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[rstest]
+    #[case(
+        b"".to_vec(),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    )]
+    #[case(
+        b"abc".to_vec(),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    )]
+    #[case(
+        b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq".to_vec(),
+        "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+    )]
+    #[case(
+        vec![b'a'; 55],
+        "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318"
+    )]
+    #[case(
+        vec![b'a'; 64],
+        "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb"
+    )]
+    #[case(
+        vec![b'a'; 1_000_000],
+        "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0"
+    )]
+    fn hashes_like_sha256(#[case] message: Vec<u8>, #[case] digest: &str) {
+        assert_eq!(hex(&sha256(&message)), digest);
+    }
+
+    #[rstest]
+    #[case(0, "43b06587ccfc1b9107634e93720bfc9a6ae0199d7d30680f126e3c8b993e7451")]
+    #[case(42, "69e28343ad1fc2fb8dee5f3ef08e5c8da31eda40e127610f2b85291a716f2305")]
+    #[case(
+        1_234_567_890_123,
+        "7e40e20108f1a30583ed5e31e0eeb23d5a032102ddaf8e098a3ef69345463cd1"
+    )]
+    fn commits_to_the_seed(#[case] seed: u64, #[case] hash: &str) {
+        let commitment = Commitment::to_seed(seed);
+        assert_eq!(commitment.to_string(), hash);
+        assert_eq!(commitment.short().to_string(), hash[..2 * SHORT_HASH_BYTES]);
+        assert_eq!(hex(commitment.hash()), hash);
+    }
+
+    #[rstest]
+    #[case("69e28343ad1fc2fb", true)]
+    #[case("69E28343 AD1FC2FB", true)]
+    #[case(
+        "69e28343ad1fc2fb8dee5f3ef08e5c8da31eda40e127610f2b85291a716f2305",
+        true
+    )]
+    #[case("69e28343ad1fc2f", false)]
+    #[case("69e28343ad1fc2fc", false)]
+    #[case("69e28343ad1fc2fbx", false)]
+    #[case(
+        "69e28343ad1fc2fb8dee5f3ef08e5c8da31eda40e127610f2b85291a716f23050",
+        false
+    )]
+    #[case("", false)]
+    fn matches_the_shown_hash(#[case] shown: &str, #[case] matches: bool) {
+        assert_eq!(Commitment::to_seed(42).matches(shown), matches);
+    }
+
+    #[test_log::test]
+    fn every_seed_has_its_own_hash() {
+        let mut hashes: Vec<_> = (0..1_000)
+            .map(|seed| Commitment::to_seed(seed).short())
+            .collect();
+        hashes.sort_unstable_by_key(|short| short.to_string());
+        hashes.dedup();
+        assert_eq!(hashes.len(), 1_000);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(42)]
+    #[case(u64::MAX)]
+    fn replays_two_four_eighteen_from_the_revealed_seed(#[case] seed: u64) {
+        // the game as played on the hub, one roll per break of the beam
+        let mut played = Game::from_seed(seed, Rules::TWO_FOUR_EIGHTEEN);
+        while !played.is_over() {
+            played.roll();
+        }
+        let shown = Commitment::to_seed(seed).short().to_string();
+
+        let mut replayed = Game::from_seed(0, Rules::TWO_FOUR_EIGHTEEN);
+        let score = commitment::verify(&mut replayed, seed, &shown);
+        assert_eq!(score, Ok(played.final_score().unwrap()));
+        assert_eq!(replayed.log.to_string(), played.log.to_string());
+    }
+
+    #[rstest]
+    #[case(7)]
+    #[case(1_200_000)]
+    fn replays_farkle_from_the_revealed_seed(#[case] seed: u64) {
+        let mut played = farkle::Game::new(SmallRng::seed_from_u64(seed));
+        while !played.is_over() {
+            played.roll();
+        }
+        let shown = Commitment::to_seed(seed).to_string();
+
+        let mut replayed = farkle::Game::new(SmallRng::seed_from_u64(0));
+        let score = commitment::verify(&mut replayed, seed, &shown);
+        assert_eq!(score, Ok(played.final_score().unwrap()));
+        assert_eq!(replayed.log().to_string(), played.log().to_string());
+    }
+
+    #[test_log::test]
+    fn another_seed_is_caught() {
+        let shown = Commitment::to_seed(42).short().to_string();
+        let mut game = Game::from_seed(0, Rules::TWO_FOUR_EIGHTEEN);
+        assert_eq!(
+            commitment::verify(&mut game, 43, &shown),
+            Err(VerifyError::HashMismatch)
+        );
+    }
+}
@@ -0,0 +1,181 @@
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use game_logic::dice_game::{DiceGame, Outcome};
+    use game_logic::farkle;
+    use game_logic::two_four_eighteen::log::GameLog;
+    use game_logic::two_four_eighteen::rules::Rules;
+    use game_logic::two_four_eighteen::Game;
+    use game_logic::yahtzee;
+    use pico_display::dice::Dice;
+
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    const MAX_ROLLS: usize = 10;
+
+    fn play<G>(game: &mut G) -> Outcome
+    where
+        G: DiceGame,
+    {
+        for _ in 0..MAX_ROLLS {
+            if game.is_over() {
+                break;
+            }
+            game.roll();
+        }
+        game.outcome()
+    }
+
+    fn sorted_values(dice: &Dice) -> Vec<u8> {
+        let mut values: Vec<u8> = dice.dice.iter().map(|die| die.value.as_u8()).collect();
+        values.sort_unstable();
+        values
+    }
+
+    /// The faces of the dice of the last roll that are shown as kept.
+    fn kept_in_roll<G>(game: &G) -> Vec<u8>
+    where
+        G: DiceGame,
+    {
+        let mut values: Vec<u8> = (0..game.rolled().len())
+            .filter(|&index| game.is_kept(index))
+            .map(|index| game.rolled().dice[index].value.as_u8())
+            .collect();
+        values.sort_unstable();
+        values
+    }
+
+    fn decode_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn outcome_follows_the_game(#[values(0, 1, 2, 3, 4, 5, 6, 7)] seed: u64) {
+        let mut game = Game::from_seed(seed, Rules::TWO_FOUR_EIGHTEEN);
+        assert_eq!(game.outcome(), Outcome::Playing);
+        assert_eq!(game.final_score(), None);
+
+        let outcome = play(&mut game);
+        let score = game.score() as i16;
+        match outcome {
+            Outcome::Won(_) => assert!(game.has_won()),
+            Outcome::Lost(_) => assert!(game.has_fish()),
+            Outcome::Over(_) => assert!(!game.has_won() && !game.has_fish()),
+            Outcome::Playing => panic!("game did not end"),
+        }
+        assert_eq!(game.final_score(), Some(score));
+        assert!(DiceGame::kept(&game) == &game.picked);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn reset_only_clears_the_game() {
+        let mut game = Game::from_seed(2418, Rules::TWO_FOUR_EIGHTEEN);
+        let mut reseeded = Game::from_seed(2418, Rules::TWO_FOUR_EIGHTEEN);
+        play(&mut game);
+        play(&mut reseeded);
+        DiceGame::reset(&mut game);
+        DiceGame::reseed(&mut reseeded, 2418);
+
+        assert!(!game.is_over());
+        assert!(game.log.is_empty());
+        assert_eq!(game.log.seed, None);
+        // the dice roll on instead of starting over from the seed
+        play(&mut game);
+        play(&mut reseeded);
+        assert_ne!(game.log.events(), reseeded.log.events());
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn logged_hex_can_be_replayed() {
+        let mut game = Game::from_seed(18, Rules::TWO_FOUR_EIGHTEEN);
+        play(&mut game);
+        assert_eq!(game.last_event().map(|event| event.dice_left), Some(0));
+
+        let bytes = decode_hex(&game.log().to_string());
+        let replayed = GameLog::from_bytes(&bytes)
+            .unwrap()
+            .replay(Rules::TWO_FOUR_EIGHTEEN)
+            .unwrap();
+        assert_eq!(replayed.outcome(), game.outcome());
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn shows_the_dice_kept_from_each_roll(#[values(0, 1, 2, 3, 4, 5, 6, 7)] seed: u64) {
+        let mut game = Game::from_seed(seed, Rules::TWO_FOUR_EIGHTEEN);
+        while !game.is_over() {
+            let picked_before_roll = game.picked.len();
+            game.roll();
+
+            let kept_before_roll = game.kept_before_roll();
+            assert_eq!(kept_before_roll.len(), picked_before_roll);
+            assert!(kept_before_roll == &game.picked.dice[..picked_before_roll]);
+            // five dice on the screen after every roll
+            assert_eq!(kept_before_roll.len() + game.rolled.len(), 5);
+            let kept = game.last_event().unwrap().kept();
+            assert_eq!(kept_in_roll(&game), sorted_values(&kept));
+        }
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn nothing_is_kept_before_the_pick() {
+        let mut game = Game::from_seed(5, Rules::TWO_FOUR_EIGHTEEN);
+        game.roll();
+        let picked = game.picked.len();
+        game.throw().unwrap();
+
+        assert_eq!(game.kept_before_roll().len(), picked);
+        assert!((0..game.rolled.len()).all(|index| !game.is_kept(index)));
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn same_faces_are_kept_in_the_order_they_were_rolled(
+        #[values(0, 1, 2, 3, 4, 5, 6, 7)] seed: u64,
+    ) {
+        let mut game = Game::from_seed(seed, Rules::TWO_FOUR_EIGHTEEN);
+        game.roll();
+        for (index, die) in game.rolled.dice.iter().enumerate() {
+            let same_before = game.rolled.dice[..index]
+                .iter()
+                .filter(|other| other.value == die.value)
+                .count();
+            if game.is_kept(index) && same_before > 0 {
+                assert!((0..index)
+                    .filter(|&other| game.rolled.dice[other].value == die.value)
+                    .all(|other| game.is_kept(other)));
+            }
+        }
+        assert!(!game.is_kept(game.rolled.len()));
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn shows_the_farkle_dice_kept_in_the_turn(#[values(0, 1, 2, 3)] seed: u64) {
+        let mut game = farkle::Game::new(SmallRng::seed_from_u64(seed));
+        for _ in 0..MAX_ROLLS {
+            game.roll();
+            let kept = &game.last_event().unwrap().kept;
+            assert_eq!(game.kept_before_roll().len() + kept.len(), game.kept.len());
+            assert_eq!(kept_in_roll(&game), sorted_values(kept));
+        }
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn other_games_show_only_the_roll() {
+        let mut game = yahtzee::Game::new(SmallRng::seed_from_u64(1));
+        game.roll();
+        assert!(game.kept_before_roll().is_empty());
+        assert!((0..game.rolled().len()).all(|index| !game.is_kept(index)));
+    }
+}
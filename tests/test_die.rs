@@ -1,93 +1,111 @@
+mod headless;
+
 #[cfg(test)]
 mod tests {
-    use rstest::fixture;
     use rstest::rstest;
 
-    use core::convert::Infallible;
-    use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
-    use embedded_graphics_simulator::{OutputSettingsBuilder, SimulatorDisplay, Window};
-    use lazy_static::lazy_static;
+    use core::ops::RangeInclusive;
+    use embedded_graphics::prelude::*;
+    use embedded_graphics::primitives::Rectangle;
+    use pico_display::dice::Dice;
     use pico_display::die::{Die, FaceValue};
-    use std::sync::Mutex;
 
-    lazy_static! {
-        static ref TEST_MUTEX: Mutex<()> = Mutex::new(());
-    }
+    use crate::headless::{assert_golden, Frame};
 
     const SCREEN_WIDTH: u32 = 255;
     const SCREEN_HEIGHT: u32 = SCREEN_WIDTH;
-
-    type Display = SimulatorDisplay<BinaryColor>;
-
-    #[fixture]
-    fn init_display() -> Display {
-        SimulatorDisplay::new(Size::new(SCREEN_WIDTH, SCREEN_HEIGHT))
-    }
-
-    fn draw_in_window(display: &Display) -> Result<(), Infallible> {
-        let output_settings = OutputSettingsBuilder::new().scale(1).build();
-        Window::new("a die", &output_settings).show_static(&display);
-
-        Ok(())
+    /// Side lengths from tiny dice up to the ones of a single die on the 128x64 display, where
+    /// the pips move and grow by a pixel from one size to the next.
+    const SIDES: RangeInclusive<u32> = 6..=64;
+    const FACES: [FaceValue; 6] = [
+        FaceValue::One,
+        FaceValue::Two,
+        FaceValue::Three,
+        FaceValue::Four,
+        FaceValue::Five,
+        FaceValue::Six,
+    ];
+
+    /// Dice showing one to six over and over.
+    fn counting_dice(number_of_dice: usize) -> Dice {
+        let dice: Vec<Die> = FACES
+            .into_iter()
+            .cycle()
+            .take(number_of_dice)
+            .map(Die::new)
+            .collect();
+        dice.into()
     }
 
     #[rstest]
+    #[case::one(FaceValue::One, "one")]
+    #[case::two(FaceValue::Two, "two")]
+    #[case::three(FaceValue::Three, "three")]
+    #[case::four(FaceValue::Four, "four")]
+    #[case::five(FaceValue::Five, "five")]
+    #[case::six(FaceValue::Six, "six")]
     #[test_log::test]
-    fn draw_face_one(#[from(init_display)] mut display: Display) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-
-        let mut die = Die::new(FaceValue::One);
-        die.draw(&mut display)?;
-        draw_in_window(&display)
+    fn draw_face(#[case] value: FaceValue, #[case] name: &str) {
+        let frame = Frame::render(SCREEN_WIDTH, SCREEN_HEIGHT, |frame| {
+            Die::new(value).draw(frame)
+        });
+        assert_golden("die", &format!("face-{name}"), &frame);
     }
 
+    /// Every size side by side, aligned at the top.
     #[rstest]
+    #[case::one(FaceValue::One, "one")]
+    #[case::two(FaceValue::Two, "two")]
+    #[case::three(FaceValue::Three, "three")]
+    #[case::four(FaceValue::Four, "four")]
+    #[case::five(FaceValue::Five, "five")]
+    #[case::six(FaceValue::Six, "six")]
     #[test_log::test]
-    fn draw_face_two(#[from(init_display)] mut display: Display) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-
-        let mut die = Die::new(FaceValue::Two);
-        die.draw(&mut display)?;
-        draw_in_window(&display)
+    fn draw_face_at_every_size(#[case] value: FaceValue, #[case] name: &str) {
+        let width = SIDES.sum();
+        let frame = Frame::render(width, *SIDES.end(), |frame| {
+            let mut x = 0;
+            for side in SIDES {
+                let area = Rectangle::new(Point::new(x, 0), Size::new_equal(side));
+                Die::new(value).draw(&mut frame.cropped(&area))?;
+                x += side as i32;
+            }
+            Ok(())
+        });
+        assert_eq!(frame.outside, 0);
+        assert_golden("die", &format!("sizes-{name}"), &frame);
     }
 
     #[rstest]
     #[test_log::test]
-    fn draw_face_three(#[from(init_display)] mut display: Display) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-
-        let mut die = Die::new(FaceValue::Three);
-        die.draw(&mut display)?;
-        draw_in_window(&display)
+    fn lay_out_dice_on_the_display(#[values(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)] n: usize) {
+        let frame = Frame::render(128, 64, |frame| counting_dice(n).draw(frame));
+        assert_eq!(frame.outside, 0);
+        assert_golden("dice", &format!("grid-{n}-128x64"), &frame);
     }
 
     #[rstest]
     #[test_log::test]
-    fn draw_face_four(#[from(init_display)] mut display: Display) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-
-        let mut die = Die::new(FaceValue::Four);
-        die.draw(&mut display)?;
-        draw_in_window(&display)
+    fn lay_out_dice_on_a_square(#[values(1, 2, 3, 4, 5, 6)] n: usize) {
+        let frame = Frame::render(64, 64, |frame| counting_dice(n).draw(frame));
+        assert_eq!(frame.outside, 0);
+        assert_golden("dice", &format!("grid-{n}-64x64"), &frame);
     }
 
     #[rstest]
     #[test_log::test]
-    fn draw_face_five(#[from(init_display)] mut display: Display) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-
-        let mut die = Die::new(FaceValue::Five);
-        die.draw(&mut display)?;
-        draw_in_window(&display)
+    fn lay_out_dice_in_a_column(#[values(1, 2, 3, 4, 5, 6)] n: usize) {
+        let frame = Frame::render(32, 128, |frame| counting_dice(n).draw(frame));
+        assert_eq!(frame.outside, 0);
+        assert_golden("dice", &format!("grid-{n}-32x128"), &frame);
     }
 
     #[rstest]
+    #[case::none(0, [0, 0, 0, 0, 0, 0])]
+    #[case::some(4, [1, 1, 1, 1, 0, 0])]
+    #[case::more_than_faces(8, [2, 2, 1, 1, 1, 1])]
     #[test_log::test]
-    fn draw_face_six(#[from(init_display)] mut display: Display) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-
-        let mut die = Die::new(FaceValue::Six);
-        die.draw(&mut display)?;
-        draw_in_window(&display)
+    fn dice_are_counted_per_face(#[case] number_of_dice: usize, #[case] counts: [u8; 6]) {
+        assert_eq!(counting_dice(number_of_dice).face_counts(), counts);
     }
 }
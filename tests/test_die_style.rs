@@ -0,0 +1,168 @@
+mod headless;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use embedded_graphics::prelude::*;
+    use embedded_graphics::primitives::Rectangle;
+    use pico_display::dice::{Dice, RenderStyle};
+    use pico_display::die::{Die, DieFace, DieStyle, FaceValue, Pips};
+
+    use crate::headless::{self, Frame};
+
+    /// The side length of every face in the golden images.
+    const SIDE: u32 = 32;
+    const FACES: [FaceValue; 6] = [
+        FaceValue::One,
+        FaceValue::Two,
+        FaceValue::Three,
+        FaceValue::Four,
+        FaceValue::Five,
+        FaceValue::Six,
+    ];
+
+    /// All six faces in a row, drawn as they are and inverted below them.
+    fn render(style: &DieStyle) -> Frame {
+        let mut frame = Frame::new(FACES.len() as u32 * SIDE, 2 * SIDE);
+        for (column, value) in FACES.into_iter().enumerate() {
+            for (row, inverted) in [false, true].into_iter().enumerate() {
+                let area = Rectangle::new(
+                    Point::new(column as i32 * SIDE as i32, row as i32 * SIDE as i32),
+                    Size::new_equal(SIDE),
+                );
+                Die::new(value)
+                    .draw_themed(&mut frame.cropped(&area), style, inverted)
+                    .unwrap();
+            }
+        }
+        frame
+    }
+
+    fn assert_golden(name: &str, frame: &Frame) {
+        headless::assert_golden("die_style", name, frame);
+    }
+
+    #[rstest]
+    fn every_combination_matches_its_golden_image(
+        #[values(false, true)] solid: bool,
+        #[values(1, 3)] border_width: u32,
+        #[values(Pips::Round, Pips::Square, Pips::Diamond, Pips::Numeral)] pips: Pips,
+        #[values(false, true)] sharp: bool,
+    ) {
+        let mut style = DieStyle::CLASSIC
+            .with_border_width(border_width)
+            .with_pips(pips);
+        if solid {
+            style = style.solid();
+        }
+        if sharp {
+            style = style.sharp();
+        }
+        let name = format!(
+            "{}-border{}-{}-{}",
+            if solid { "solid" } else { "outlined" },
+            border_width,
+            format!("{pips:?}").to_lowercase(),
+            if sharp { "sharp" } else { "rounded" },
+        );
+        assert_golden(&name, &render(&style));
+    }
+
+    #[rstest]
+    #[case("radius20", DieStyle::CLASSIC.with_corner_radius(20))]
+    #[case("radius50", DieStyle::CLASSIC.with_corner_radius(50))]
+    #[case("pips9", DieStyle::CLASSIC.with_pip_size(9))]
+    #[case("pips19", DieStyle::CLASSIC.with_pip_size(19))]
+    #[case("solid-square-pips19", DieStyle::CLASSIC.solid().with_pips(Pips::Square).with_pip_size(19))]
+    fn sizes_match_their_golden_image(#[case] name: &str, #[case] style: DieStyle) {
+        assert_golden(name, &render(&style));
+    }
+
+    #[rstest]
+    fn themes_match_their_golden_image(#[values(0, 1, 2, 3, 4, 5)] index: usize) {
+        let (name, style) = DieStyle::THEMES[index];
+        assert_golden(&format!("theme-{}", name.to_lowercase()), &render(&style));
+    }
+
+    #[test_log::test]
+    fn classic_theme_is_the_default_look() {
+        let mut plain = Frame::new(FACES.len() as u32 * SIDE, 2 * SIDE);
+        for (column, value) in FACES.into_iter().enumerate() {
+            let die = Die::new(value);
+            let area = |row: i32| {
+                Rectangle::new(
+                    Point::new(column as i32 * SIDE as i32, row * SIDE as i32),
+                    Size::new_equal(SIDE),
+                )
+            };
+            DieFace::draw(&die, &mut plain.cropped(&area(0))).unwrap();
+            DieFace::draw_inverted(&die, &mut plain.cropped(&area(1))).unwrap();
+        }
+        assert!(render(&DieStyle::CLASSIC) == plain);
+        assert_eq!(DieStyle::default(), DieStyle::CLASSIC);
+        assert_eq!(DieStyle::THEMES[0].1, DieStyle::CLASSIC);
+    }
+
+    #[test_log::test]
+    fn solid_dice_are_outlined_when_inverted() {
+        let solid = render(&DieStyle::CLASSIC.solid());
+        let outlined = render(&DieStyle::CLASSIC);
+        let half = |frame: &Frame, row: usize| {
+            let rows = frame.pixels.chunks(frame.size.width as usize);
+            rows.skip(row * SIDE as usize)
+                .take(SIDE as usize)
+                .flatten()
+                .copied()
+                .collect::<Vec<bool>>()
+        };
+        assert_eq!(half(&solid, 0), half(&outlined, 1));
+        assert_eq!(half(&solid, 1), half(&outlined, 0));
+    }
+
+    #[test_log::test]
+    fn thicker_borders_light_more_pixels() {
+        let lit = |frame: &Frame| frame.pixels.iter().filter(|&&on| on).count();
+        let thin = render(&DieStyle::CLASSIC);
+        let thick = render(&DieStyle::CLASSIC.with_border_width(3));
+        assert!(lit(&thick) > lit(&thin));
+    }
+
+    #[test_log::test]
+    fn golden_images_survive_the_round_trip() {
+        let frame = render(&DieStyle::THEMES[5].1);
+        assert!(Frame::from_pbm(&frame.to_pbm()) == frame);
+    }
+
+    #[rstest]
+    #[case(8)]
+    #[case(13)]
+    #[case(21)]
+    #[case(64)]
+    fn every_theme_stays_inside_the_die(#[case] side: u32) {
+        for (name, style) in DieStyle::THEMES {
+            for value in FACES {
+                for inverted in [false, true] {
+                    let mut frame = Frame::new(side, side);
+                    Die::new(value)
+                        .draw_themed(&mut frame, &style, inverted)
+                        .unwrap();
+                    assert_eq!(frame.outside, 0, "{name} at {side} px");
+                }
+            }
+        }
+    }
+
+    #[test_log::test]
+    fn dice_are_drawn_in_the_theme_of_their_render_style() {
+        let dice: Dice = FACES.map(Die::new).to_vec().into();
+        let theme = DieStyle::THEMES[4].1;
+        let mut themed = Frame::new(128, 64);
+        dice.draw_styled(&mut themed, |_| RenderStyle::PLAIN.themed(theme))
+            .unwrap();
+        let mut plain = Frame::new(128, 64);
+        dice.draw(&mut plain).unwrap();
+        assert!(themed != plain);
+        assert!(themed.pixels.iter().any(|&on| on));
+    }
+}
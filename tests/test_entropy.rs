@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use game_logic::dice_game::DiceGame;
+    use game_logic::entropy::{EntropyPool, Source};
+    use game_logic::two_four_eighteen::rules::Rules;
+    use game_logic::two_four_eighteen::Game;
+    use game_logic::{farkle, pig, yahtzee};
+    use pico_display::dice::Dice;
+
+    use rand::rngs::SmallRng;
+    use rand::{RngCore, SeedableRng};
+
+    const DRAWS: usize = 10_000;
+
+    fn pool_of(samples: &[(Source, u64)]) -> EntropyPool {
+        let mut pool = EntropyPool::new();
+        for &(source, sample) in samples {
+            pool.mix(source, sample);
+        }
+        pool
+    }
+
+    fn values(dice: &Dice) -> Vec<u8> {
+        dice.dice.iter().map(|die| die.value.as_u8()).collect()
+    }
+
+    /// Plays a game until it is over and returns every roll.
+    fn rolls<G>(game: &mut G) -> Vec<Vec<u8>>
+    where
+        G: DiceGame,
+    {
+        let mut rolls = Vec::new();
+        while !game.is_over() && rolls.len() < DRAWS {
+            game.roll();
+            rolls.push(values(game.rolled()));
+        }
+        rolls
+    }
+
+    #[test_log::test]
+    fn same_samples_draw_the_same_seeds() {
+        let samples = [(Source::Timer, 12_345), (Source::BeamTiming, 1_200_000)];
+        let mut pool = pool_of(&samples);
+        let mut same = pool_of(&samples);
+        for _ in 0..10 {
+            assert_eq!(pool.next_u64(), same.next_u64());
+        }
+        assert_eq!(pool.samples(), 2);
+    }
+
+    #[rstest]
+    #[case(Source::BeamTiming, 1_200_001)]
+    #[case(Source::AdcNoise, 1_200_000)]
+    #[case(Source::Timer, 1_200_000)]
+    fn any_different_sample_changes_the_seed(#[case] source: Source, #[case] sample: u64) {
+        let mut seed = pool_of(&[(Source::BeamTiming, 1_200_000)]);
+        let mut other = pool_of(&[(source, sample)]);
+        assert_ne!(seed.next_u64(), other.next_u64());
+    }
+
+    #[test_log::test]
+    fn order_of_samples_matters() {
+        let mut forwards = pool_of(&[(Source::AdcNoise, 1), (Source::AdcNoise, 2)]);
+        let mut backwards = pool_of(&[(Source::AdcNoise, 2), (Source::AdcNoise, 1)]);
+        assert_ne!(forwards.next_u64(), backwards.next_u64());
+    }
+
+    #[test_log::test]
+    fn draws_never_repeat_without_new_samples() {
+        let mut pool = pool_of(&[(Source::Timer, 0)]);
+        let mut seeds: Vec<u64> = (0..DRAWS).map(|_| pool.next_u64()).collect();
+        seeds.sort_unstable();
+        seeds.dedup();
+        assert_eq!(seeds.len(), DRAWS);
+    }
+
+    #[test_log::test]
+    fn a_single_bit_flips_about_half_of_the_seed() {
+        let mut flipped_bits = 0;
+        for bit in 0..u64::BITS {
+            let mut seed = pool_of(&[(Source::BeamTiming, 0)]);
+            let mut flipped = pool_of(&[(Source::BeamTiming, 1 << bit)]);
+            flipped_bits += (seed.next_u64() ^ flipped.next_u64()).count_ones();
+        }
+        let average = flipped_bits as f64 / u64::BITS as f64;
+        assert!((24.0..=40.0).contains(&average), "{average} bits flipped");
+    }
+
+    #[test_log::test]
+    fn every_bit_is_set_about_half_of_the_time() {
+        let mut pool = pool_of(&[(Source::RingOscillator, 0xa5)]);
+        let mut counts = [0; 64];
+        for _ in 0..DRAWS {
+            let seed = pool.next_u64();
+            for (bit, count) in counts.iter_mut().enumerate() {
+                *count += (seed >> bit & 1) as usize;
+            }
+        }
+        for count in counts {
+            assert!((4_700..=5_300).contains(&count), "bit set {count} times");
+        }
+    }
+
+    #[test_log::test]
+    fn pool_seeds_a_game_directly() {
+        let mut pool = pool_of(&[(Source::BeamTiming, 1_500_000)]);
+        let mut same = pool.clone();
+        let mut game = Game::new(SmallRng::from_rng(&mut pool), Rules::TWO_FOUR_EIGHTEEN);
+        let mut other = Game::new(SmallRng::from_rng(&mut same), Rules::TWO_FOUR_EIGHTEEN);
+        assert_eq!(rolls(&mut game), rolls(&mut other));
+    }
+
+    #[test_log::test]
+    fn reseeding_replays_two_four_eighteen() {
+        let mut game = Game::from_seed(7, Rules::TWO_FOUR_EIGHTEEN);
+        let first = rolls(&mut game);
+        DiceGame::reseed(&mut game, 7);
+        assert_eq!(rolls(&mut game), first);
+        assert_eq!(game.log.seed, Some(7));
+    }
+
+    #[rstest]
+    #[case(7, 8)]
+    #[case(1, 2)]
+    fn reseeding_restarts_every_game(#[case] seed: u64, #[case] other_seed: u64) {
+        let mut farkle = farkle::Game::new(SmallRng::seed_from_u64(0));
+        let mut yahtzee = yahtzee::Game::new(SmallRng::seed_from_u64(0));
+        let mut pig = pig::Game::new(SmallRng::seed_from_u64(0), pig::DEFAULT_TARGET_SCORE);
+
+        farkle.reseed(seed);
+        let farkle_rolls = rolls(&mut farkle);
+        farkle.reseed(seed);
+        assert_eq!(rolls(&mut farkle), farkle_rolls);
+        farkle.reseed(other_seed);
+        assert_ne!(rolls(&mut farkle), farkle_rolls);
+
+        yahtzee.reseed(seed);
+        let yahtzee_rolls = rolls(&mut yahtzee);
+        yahtzee.reseed(seed);
+        assert_eq!(yahtzee.total(), 0);
+        assert_eq!(rolls(&mut yahtzee), yahtzee_rolls);
+
+        pig.reseed(seed);
+        let pig_rolls = rolls(&mut pig);
+        pig.reseed(seed);
+        assert_eq!(pig.score(pig::Player::Human), 0);
+        assert_eq!(rolls(&mut pig), pig_rolls);
+    }
+}
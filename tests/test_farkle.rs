@@ -0,0 +1,152 @@
+mod fixtures;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use game_logic::dice_game::{DiceGame, Outcome};
+    use game_logic::farkle::scoring::{self, is_farkle, scoring_dice};
+    use game_logic::farkle::strategy::{DefaultStrategy, Strategy};
+    use game_logic::farkle::{Action, Game, MAX_TURNS, NUMBER_OF_DICE, TARGET_SCORE};
+    use pico_display::dice::Dice;
+
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    use crate::fixtures::dice;
+
+    const MAX_ROLLS: usize = 1000;
+
+    fn faces(dice: &Dice) -> Vec<u8> {
+        let mut faces: Vec<u8> = dice.dice.iter().map(|die| die.value.as_u8()).collect();
+        faces.sort();
+        faces
+    }
+
+    #[rstest]
+    #[case::single_one(&[1], 100)]
+    #[case::single_five(&[5], 50)]
+    #[case::two_singles(&[1, 5], 150)]
+    #[case::three_ones(&[1, 1, 1], 1000)]
+    #[case::three_twos(&[2, 2, 2], 200)]
+    #[case::three_threes(&[3, 3, 3], 300)]
+    #[case::three_fours(&[4, 4, 4], 400)]
+    #[case::three_fives(&[5, 5, 5], 500)]
+    #[case::three_sixes(&[6, 6, 6], 600)]
+    #[case::triple_and_single(&[4, 4, 4, 1], 500)]
+    #[case::four_of_a_kind(&[3, 3, 3, 3], 1000)]
+    #[case::four_ones(&[1, 1, 1, 1], 1100)]
+    #[case::five_of_a_kind(&[2, 2, 2, 2, 2], 2000)]
+    #[case::six_of_a_kind(&[6, 6, 6, 6, 6, 6], 3000)]
+    #[case::straight(&[3, 1, 4, 6, 5, 2], 1500)]
+    #[case::three_pairs(&[2, 2, 4, 4, 6, 6], 1500)]
+    #[case::three_pairs_of_singles(&[1, 1, 5, 5, 3, 3], 1500)]
+    #[case::two_triples(&[2, 2, 2, 3, 3, 3], 500)]
+    #[test_log::test]
+    fn scoring_combinations(#[case] kept: &[u8], #[case] score: u16) {
+        assert_eq!(scoring::score(&dice(kept)), Some(score));
+    }
+
+    #[rstest]
+    #[case::two(&[2])]
+    #[case::pair(&[3, 3])]
+    #[case::single_with_dead_die(&[1, 4])]
+    #[case::short_straight(&[1, 2, 3, 4, 5])]
+    #[test_log::test]
+    fn dead_dice_do_not_score(#[case] kept: &[u8]) {
+        assert_eq!(scoring::score(&dice(kept)), None);
+    }
+
+    #[rstest]
+    #[case::nothing(&[2, 3, 4, 6, 6, 2], true)]
+    #[case::single_five(&[2, 3, 4, 6, 5, 2], false)]
+    #[case::one_die(&[4], true)]
+    #[case::triple(&[2, 2, 2], false)]
+    #[test_log::test]
+    fn farkle_without_scoring_dice(#[case] rolled: &[u8], #[case] farkle: bool) {
+        assert_eq!(is_farkle(&dice(rolled)), farkle);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn scoring_dice_are_found() {
+        assert_eq!(
+            faces(&scoring_dice(&dice(&[1, 2, 3, 3, 3, 5]))),
+            [1, 3, 3, 3, 5]
+        );
+        assert_eq!(faces(&scoring_dice(&dice(&[2, 2, 4, 4, 6, 6]))).len(), 6);
+    }
+
+    #[rstest]
+    #[case::hot_dice(&[1, 2, 3, 4, 5, 6], &[1, 2, 3, 4, 5, 6])]
+    #[case::combination_and_ones(&[1, 1, 4, 4, 4, 2], &[1, 1, 4, 4, 4])]
+    #[case::single_one(&[1, 1, 2, 3, 5, 6], &[1])]
+    #[case::single_five(&[5, 5, 2, 3, 4, 6], &[5])]
+    #[test_log::test]
+    fn default_strategy_keeps(#[case] rolled: &[u8], #[case] kept: &[u8]) {
+        assert_eq!(faces(&DefaultStrategy.keep(&dice(rolled), 0)), kept);
+    }
+
+    #[rstest]
+    #[case::hot_dice(NUMBER_OF_DICE, 1900, TARGET_SCORE, false)]
+    #[case::many_dice_left(4, 900, TARGET_SCORE, false)]
+    #[case::few_dice_left(2, 300, TARGET_SCORE, true)]
+    #[case::target_reached(5, 1000, 1000, true)]
+    #[test_log::test]
+    fn default_strategy_banks(
+        #[case] dice_left: u8,
+        #[case] turn_score: u16,
+        #[case] needed: u16,
+        #[case] bank: bool,
+    ) {
+        assert_eq!(DefaultStrategy.bank(turn_score, dice_left, needed), bank);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn farkle_loses_the_turn_score(#[values(0, 1, 2, 3)] seed: u64) {
+        let mut game = Game::new(SmallRng::seed_from_u64(seed));
+        let mut farkles = 0;
+        while !game.is_over() {
+            let total = game.total;
+            game.roll();
+            let event = game.last_event().unwrap();
+            match event.action {
+                Action::Farkle => {
+                    assert_eq!(game.turn_score, 0);
+                    assert_eq!(game.total, total);
+                    assert!(event.kept.is_empty());
+                    farkles += 1;
+                }
+                Action::HotDice => assert_eq!(game.dice_left, NUMBER_OF_DICE),
+                Action::Bank => assert_eq!(game.total, total + game.turn_score),
+                Action::Risk => assert!(game.dice_left < NUMBER_OF_DICE),
+            }
+        }
+        assert!(farkles > 0);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn game_ends_by_target_or_turns(#[values(0, 1, 2, 3, 4, 5, 6, 7)] seed: u64) {
+        let mut game = Game::new(SmallRng::seed_from_u64(seed));
+        for _ in 0..MAX_ROLLS {
+            if game.is_over() {
+                break;
+            }
+            game.roll();
+        }
+
+        let banked: u16 = game.turns().scores().iter().sum();
+        assert_eq!(banked, game.total);
+        match game.outcome() {
+            Outcome::Won(total) => assert!(total as u16 >= TARGET_SCORE),
+            Outcome::Lost(_) => assert_eq!(game.turns().len(), MAX_TURNS),
+            outcome => panic!("unexpected outcome {outcome:?}"),
+        }
+
+        DiceGame::reset(&mut game);
+        assert_eq!(game.outcome(), Outcome::Playing);
+        assert!(game.turns().is_empty());
+    }
+}
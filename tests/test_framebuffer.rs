@@ -0,0 +1,226 @@
+mod headless;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use embedded_graphics::prelude::*;
+    use pico_display::dice::Dice;
+    use pico_display::die::{Die, FaceValue};
+    use pico_display::framebuffer::{DirtyFrame, PackedFrame};
+    use pico_display::messages;
+
+    use crate::headless::Frame;
+
+    type DisplayFrame = PackedFrame<128, 8>;
+    type Display = DirtyFrame<128, 8>;
+
+    /// The RAM of a display that is only sent the dirty spans of the frame.
+    struct Panel {
+        ram: [u8; 1024],
+        sent_bytes: usize,
+    }
+
+    impl Panel {
+        fn new() -> Self {
+            Self {
+                ram: [0xa5; 1024],
+                sent_bytes: 0,
+            }
+        }
+
+        fn flush(&mut self, display: &mut Display) {
+            for (page, column, bytes) in display.dirty_spans() {
+                let start = page * 128 + column;
+                self.ram[start..start + bytes.len()].copy_from_slice(bytes);
+                self.sent_bytes += bytes.len();
+            }
+            display.mark_clean();
+        }
+    }
+
+    fn dice(faces: &[FaceValue]) -> Dice {
+        let dice: Vec<Die> = faces.iter().copied().map(Die::new).collect();
+        dice.into()
+    }
+
+    fn clean_display() -> Display {
+        let mut display = Display::new();
+        display.mark_clean();
+        display
+    }
+
+    fn five_dice() -> Dice {
+        let dice: Vec<Die> = [
+            FaceValue::One,
+            FaceValue::Two,
+            FaceValue::Four,
+            FaceValue::Five,
+            FaceValue::Six,
+        ]
+        .into_iter()
+        .map(Die::new)
+        .collect();
+        dice.into()
+    }
+
+    /// The frame drawn into a one byte per pixel frame through its pixels.
+    fn unpacked(frame: &DisplayFrame) -> Frame {
+        Frame::render(128, 64, |target| target.draw_iter(frame))
+    }
+
+    #[test_log::test]
+    fn frame_of_the_display_takes_a_kilobyte() {
+        assert_eq!(core::mem::size_of::<DisplayFrame>(), 1024);
+        assert_eq!(DisplayFrame::new().as_bytes().len(), 1024);
+        assert_eq!(DisplayFrame::new().size(), Size::new(128, 64));
+        assert_eq!(PackedFrame::<128, 4>::new().size(), Size::new(128, 32));
+    }
+
+    #[rstest]
+    #[case::top_left(Point::new(0, 0), 0, 0x01)]
+    #[case::bottom_of_first_page(Point::new(0, 7), 0, 0x80)]
+    #[case::second_column(Point::new(1, 0), 1, 0x01)]
+    #[case::second_page(Point::new(1, 8), 129, 0x01)]
+    #[case::bottom_right(Point::new(127, 63), 1023, 0x80)]
+    #[test_log::test]
+    fn pixels_are_packed_like_the_ram_of_the_display(
+        #[case] point: Point,
+        #[case] index: usize,
+        #[case] byte: u8,
+    ) {
+        let mut frame = DisplayFrame::new();
+        Pixel(point, BinaryColor::On).draw(&mut frame).unwrap();
+        for (at, &actual) in frame.as_bytes().iter().enumerate() {
+            assert_eq!(actual, if at == index { byte } else { 0 }, "byte {at}");
+        }
+        assert!(frame.is_on(point));
+    }
+
+    #[test_log::test]
+    fn pixels_outside_of_the_frame_are_left_out() {
+        let mut frame = DisplayFrame::new();
+        for point in [
+            Point::new(-1, 0),
+            Point::new(0, -1),
+            Point::new(128, 0),
+            Point::new(0, 64),
+        ] {
+            Pixel(point, BinaryColor::On).draw(&mut frame).unwrap();
+            assert!(!frame.is_on(point));
+        }
+        assert!(frame == DisplayFrame::new());
+    }
+
+    #[test_log::test]
+    fn pixels_can_be_turned_off_again() {
+        let mut frame = DisplayFrame::new();
+        frame.clear(BinaryColor::On).unwrap();
+        assert!(frame.as_bytes().iter().all(|&byte| byte == 0xff));
+        Pixel(Point::new(3, 10), BinaryColor::Off)
+            .draw(&mut frame)
+            .unwrap();
+        assert!(!frame.is_on(Point::new(3, 10)));
+        assert_eq!(frame.lit_pixels().count(), 128 * 64 - 1);
+        frame.clear(BinaryColor::Off).unwrap();
+        assert!(frame == DisplayFrame::new());
+    }
+
+    #[test_log::test]
+    fn dice_look_the_same_packed() {
+        let mut packed = DisplayFrame::new();
+        five_dice().draw(&mut packed).unwrap();
+        let drawn = Frame::render(128, 64, |target| five_dice().draw(target));
+        assert!(unpacked(&packed) == drawn);
+        assert_eq!(packed.into_iter().count(), 128 * 64);
+        assert_eq!(packed.lit_pixels().count(), drawn.lit_pixels());
+    }
+
+    #[test_log::test]
+    fn new_display_is_sent_in_full() {
+        let mut display = Display::new();
+        assert_eq!(display.dirty_bytes(), 1024);
+        assert_eq!(display.dirty_spans().count(), 8);
+
+        let mut panel = Panel::new();
+        panel.flush(&mut display);
+        assert_eq!(panel.ram, [0; 1024]);
+        assert_eq!(display.dirty_bytes(), 0);
+    }
+
+    #[rstest]
+    #[case::one_pixel(&[Point::new(3, 10)], &[(1, 3, 1)])]
+    #[case::same_byte(&[Point::new(3, 8), Point::new(3, 15)], &[(1, 3, 1)])]
+    #[case::same_page(&[Point::new(3, 10), Point::new(9, 12)], &[(1, 3, 7)])]
+    #[case::two_pages(&[Point::new(3, 10), Point::new(100, 63)], &[(1, 3, 1), (7, 100, 1)])]
+    #[test_log::test]
+    fn drawing_marks_the_columns_of_its_pages(
+        #[case] points: &[Point],
+        #[case] spans: &[(usize, usize, usize)],
+    ) {
+        let mut display = clean_display();
+        for &point in points {
+            Pixel(point, BinaryColor::On).draw(&mut display).unwrap();
+        }
+        let dirty: Vec<(usize, usize, usize)> = display
+            .dirty_spans()
+            .map(|(page, column, bytes)| (page, column, bytes.len()))
+            .collect();
+        assert_eq!(dirty, spans);
+    }
+
+    #[test_log::test]
+    fn drawing_what_is_shown_marks_nothing() {
+        let mut display = clean_display();
+        display.clear(BinaryColor::Off).unwrap();
+        Pixel(Point::new(5, 5), BinaryColor::Off)
+            .draw(&mut display)
+            .unwrap();
+        assert_eq!(display.dirty_bytes(), 0);
+
+        five_dice().draw(&mut display).unwrap();
+        display.mark_clean();
+        five_dice().draw(&mut display).unwrap();
+        assert_eq!(display.dirty_bytes(), 0);
+    }
+
+    #[test_log::test]
+    fn copying_a_frame_marks_only_what_differs() {
+        let mut score = DisplayFrame::new();
+        messages::big_centered_message("12", &mut score).unwrap();
+        let mut display = clean_display();
+        display.copy_from(&score);
+        let first = display.dirty_bytes();
+        display.mark_clean();
+
+        display.copy_from(&score);
+        assert_eq!(display.dirty_bytes(), 0);
+
+        let mut higher = DisplayFrame::new();
+        messages::big_centered_message("13", &mut higher).unwrap();
+        display.copy_from(&higher);
+        assert!(display.dirty_bytes() < first);
+        assert!(*display.frame() == higher);
+    }
+
+    #[test_log::test]
+    fn panel_shows_the_frame_after_every_flush() {
+        let rolls: [&[FaceValue]; 4] = [
+            &[FaceValue::Six, FaceValue::Two, FaceValue::Three],
+            &[FaceValue::Six, FaceValue::Four, FaceValue::Three],
+            &[FaceValue::One, FaceValue::Five],
+            &[FaceValue::Four],
+        ];
+        let mut display = Display::new();
+        let mut panel = Panel::new();
+        panel.flush(&mut display);
+        for faces in rolls {
+            display.clear(BinaryColor::Off).unwrap();
+            dice(faces).draw(&mut display).unwrap();
+            panel.flush(&mut display);
+            assert_eq!(panel.ram, display.frame().as_bytes());
+        }
+        assert!(panel.sent_bytes < 1024 * (1 + rolls.len()));
+    }
+}
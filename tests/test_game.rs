@@ -1,131 +1,366 @@
+mod headless;
+
 #[cfg(test)]
 mod tests {
-    use rand;
-    use rstest::{fixture, rstest};
+    use rstest::rstest;
 
+    use game_logic::commitment::Commitment;
+    use game_logic::two_four_eighteen::log::GameLog;
+    use game_logic::two_four_eighteen::pick::Selection;
+    use game_logic::two_four_eighteen::rules::Rules;
+    use game_logic::two_four_eighteen::strategy::DefaultStrategy;
     use game_logic::two_four_eighteen::Game;
     use game_logic::two_four_eighteen::NumberOfDice;
     use pico_display::dice::Dice;
-    use pico_display::die::{Die, FaceValue};
+    use pico_display::dice::RenderStyle;
+    use pico_display::die::{Die, DieStyle, FaceValue};
     use pico_display::messages;
 
-    use core::convert::Infallible;
+    use embedded_graphics::prelude::*;
+    use embedded_graphics::primitives::Rectangle;
 
-    use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
-    use embedded_graphics_simulator::{
-        OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
-    };
     use tracing::info;
 
     use rand::rngs::SmallRng;
     use rand::SeedableRng;
 
-    use std::thread;
-    use std::time::Duration;
+    use crate::headless::{assert_golden, Frame};
 
-    const SCALE: u32 = 5;
-    const SCREEN_WIDTH: u32 = SCALE * 128;
-    const SCREEN_HEIGHT: u32 = SCALE * 64;
-    const SLEEP_FOR: Duration = Duration::from_secs(2);
+    const SCREEN_WIDTH: u32 = 128;
+    const SCREEN_HEIGHT: u32 = 64;
 
-    use lazy_static::lazy_static;
-    use std::sync::Mutex;
+    /// The height of the smaller SSD1306 panels.
+    const SHORT_SCREEN_HEIGHT: u32 = 32;
 
-    lazy_static! {
-        static ref TEST_MUTEX: Mutex<()> = Mutex::new(());
+    fn screen<F>(draw: F) -> Frame
+    where
+        F: FnOnce(&mut Frame) -> Result<(), core::convert::Infallible>,
+    {
+        Frame::render(SCREEN_WIDTH, SCREEN_HEIGHT, draw)
     }
 
-    type Display = SimulatorDisplay<BinaryColor>;
+    fn short_screen<F>(draw: F) -> Frame
+    where
+        F: FnOnce(&mut Frame) -> Result<(), core::convert::Infallible>,
+    {
+        Frame::render(SCREEN_WIDTH, SHORT_SCREEN_HEIGHT, draw)
+    }
 
-    #[fixture]
-    fn init_display() -> Display {
-        SimulatorDisplay::new(Size::new(SCREEN_WIDTH, SCREEN_HEIGHT))
+    /// The first of the wordings of a screen of small text, as the firmware words them, that
+    /// fits on a short screen.
+    fn short_wording(wordings: [String; 2]) -> String {
+        let size = Size::new(SCREEN_WIDTH, SHORT_SCREEN_HEIGHT);
+        wordings
+            .into_iter()
+            .find(|wording| messages::small_message_fits(wording, size))
+            .unwrap()
     }
 
-    #[fixture]
-    fn gen_small_rng() -> SmallRng {
-        let seed: u64 = rand::random();
-        SmallRng::seed_from_u64(seed)
+    /// The message shown once the game is over, as the firmware words it.
+    fn result_message(game: &Game) -> String {
+        if game.has_fish() {
+            "Fish!".to_string()
+        } else if game.has_won() {
+            format!("{}!\nYou Win!", game.score())
+        } else {
+            game.score().to_string()
+        }
     }
 
     #[rstest]
     #[test_log::test]
-    fn play_game(
-        #[from(init_display)] mut display: Display,
-        #[from(gen_small_rng)] small_rng: SmallRng,
-    ) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-
-        let output_settings = OutputSettingsBuilder::new().scale(1).build();
-        let mut window = Window::new("Two Four Eighteen", &output_settings);
-
-        let mut game = Game::new(small_rng);
+    fn play_game(#[values(0, 7, 2418)] seed: u64) {
+        let mut game = Game::new(SmallRng::seed_from_u64(seed), Rules::TWO_FOUR_EIGHTEEN);
 
-        'running: while game.dice_left > NumberOfDice::Zero {
-            display.clear(BinaryColor::Off)?;
+        let mut roll = 0;
+        while game.dice_left > NumberOfDice::Zero {
             game.roll();
-            game.rolled.draw(&mut display)?;
-            window.update(&display);
+            roll += 1;
             info!("current score: {}", game.score());
-
-            if window.events().any(|e| e == SimulatorEvent::Quit) {
-                break 'running;
-            }
-            thread::sleep(SLEEP_FOR);
-            display.clear(BinaryColor::Off)?;
+            let frame = screen(|frame| game.rolled.draw(frame));
+            assert_golden("game", &format!("play-{seed}-roll-{roll}"), &frame);
         }
-        let mut picked: Vec<String> = game
-            .picked
-            .dice
-            .iter()
-            .map(|die| die.value.as_u8().to_string())
-            .collect();
-        picked.sort();
-        info!("picked: {}", picked.join(","));
-        let score = game.score();
-        info!("final score: {}", score);
-        display.clear(BinaryColor::Off)?;
-        if game.has_fish() {
-            messages::big_centered_message("Fish!", &mut display).unwrap();
-        } else if game.has_won() {
-            messages::big_centered_message("18! You Win!", &mut display).unwrap();
-        } else {
-            messages::big_centered_message(score.to_string().as_str(), &mut display).unwrap();
-        }
-        window.update(&display);
-        thread::sleep(SLEEP_FOR);
-        Ok(())
+        info!("final score: {}", game.score());
+
+        let message = result_message(&game);
+        let frame = screen(|frame| {
+            messages::big_centered_message(&message, frame).unwrap();
+            Ok(())
+        });
+        assert_golden("game", &format!("play-{seed}-result"), &frame);
     }
 
     #[rstest]
     #[test_log::test]
-    fn start_game_with_no_fish(
-        #[from(init_display)] mut display: Display,
-        #[from(gen_small_rng)] small_rng: SmallRng,
-    ) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-
-        let output_settings = OutputSettingsBuilder::new().scale(1).build();
-        let mut window = Window::new("Two Four Eighteen (No Fish)", &output_settings);
-
+    fn start_game_with_no_fish() {
         let picked = vec![Die::new(FaceValue::Two), Die::new(FaceValue::Four)];
         let mut game = Game {
             dice_left: NumberOfDice::Three,
-            small_rng,
+            small_rng: SmallRng::seed_from_u64(18),
             picked: Dice::from(picked),
             rolled: Dice::empty(),
+            strategy: DefaultStrategy,
+            log: GameLog::new(None),
+            rules: Rules::TWO_FOUR_EIGHTEEN,
         };
         game.roll();
 
-        game.rolled.draw(&mut display)?;
-        window.update(&display);
-        thread::sleep(SLEEP_FOR);
+        assert_golden(
+            "game",
+            "no-fish-rolled",
+            &screen(|frame| game.rolled.draw(frame)),
+        );
+        assert_golden(
+            "game",
+            "no-fish-picked",
+            &screen(|frame| game.picked.draw(frame)),
+        );
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn pick_dice() {
+        let mut game = Game::new(SmallRng::seed_from_u64(4), Rules::TWO_FOUR_EIGHTEEN);
+        game.throw().unwrap();
+
+        let mut selection = Selection::new(game.rolled.len());
+        for step in 0..game.rolled.len() {
+            let frame = screen(|frame| {
+                game.rolled
+                    .draw_selection(frame, selection.cursor(), |index| {
+                        selection.is_selected(index)
+                    })
+            });
+            assert_golden("game", &format!("pick-{step}"), &frame);
+
+            selection.toggle();
+            selection.next();
+        }
+        game.keep(&selection).unwrap();
+        info!("current score: {}", game.score());
+    }
+
+    #[rstest]
+    #[case::fish("big-fish", "Fish!")]
+    #[case::win("big-win", "18!\nYou Win!")]
+    #[case::score("big-score", "12")]
+    #[test_log::test]
+    fn draw_big_message(#[case] name: &str, #[case] message: &str) {
+        let frame = screen(|frame| {
+            messages::big_centered_message(message, frame).unwrap();
+            Ok(())
+        });
+        assert_eq!(frame.outside, 0);
+        assert_golden("messages", name, &frame);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn draw_medium_sized_message() {
+        let frame = screen(|frame| {
+            messages::medium_sized_centered_message(
+                "Break the beam for\n at least one second\n to start the game.",
+                frame,
+            )
+            .unwrap();
+            Ok(())
+        });
+        assert_eq!(frame.outside, 0);
+        assert_golden("messages", "medium-start", &frame);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn draw_small_message() {
+        let lines: Vec<String> = (1..=7)
+            .map(|line| format!("{line}: 0123456789 ABCDEFGHIJK"))
+            .collect();
+        let frame = screen(|frame| {
+            messages::small_message(&lines.join("\n"), frame).unwrap();
+            Ok(())
+        });
+        assert_eq!(frame.outside, 0);
+        assert_golden("messages", "small-seven-lines", &frame);
+    }
+
+    #[rstest]
+    #[case::fish("short-big-fish", "Fish!")]
+    #[case::win("short-big-win", "18!\nYou Win!")]
+    #[case::lost("short-big-lost", "Out of\nturns!")]
+    #[test_log::test]
+    fn big_message_fits_a_short_screen(#[case] name: &str, #[case] message: &str) {
+        let frame = Frame::render(SCREEN_WIDTH, SHORT_SCREEN_HEIGHT, |frame| {
+            messages::big_centered_message(message, frame).unwrap();
+            Ok(())
+        });
+        assert_eq!(frame.outside, 0);
+        assert!(frame.lit_pixels() > 0);
+        assert_golden("messages", name, &frame);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn medium_sized_message_fits_a_short_screen() {
+        let frame = Frame::render(SCREEN_WIDTH, SHORT_SCREEN_HEIGHT, |frame| {
+            messages::medium_sized_centered_message(
+                "Break the beam for\n at least one second\n to start the game.",
+                frame,
+            )
+            .unwrap();
+            Ok(())
+        });
+        assert_eq!(frame.outside, 0);
+        assert_golden("messages", "short-medium-start", &frame);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn small_message_leaves_out_blank_lines_on_a_short_screen() {
+        let message = "Seed of the game:\n2418\n\nHash: 1a2b3c4d";
+        let short = Frame::render(SCREEN_WIDTH, SHORT_SCREEN_HEIGHT, |frame| {
+            messages::small_message(message, frame).unwrap();
+            Ok(())
+        });
+        let dense = Frame::render(SCREEN_WIDTH, SHORT_SCREEN_HEIGHT, |frame| {
+            messages::small_message("Seed of the game:\n2418\nHash: 1a2b3c4d", frame).unwrap();
+            Ok(())
+        });
+        assert_eq!(short.outside, 0);
+        assert!(short == dense);
+        assert_golden("messages", "short-small-seed", &short);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn draw_status_over_dice() {
+        let dice: Vec<Die> = [FaceValue::Six, FaceValue::Five, FaceValue::Four]
+            .into_iter()
+            .map(Die::new)
+            .collect();
+        let dice = Dice::from(dice);
+        let frame = screen(|frame| {
+            dice.draw(frame)?;
+            messages::status_message("Player 2", frame).unwrap();
+            Ok(())
+        });
+        assert_eq!(frame.outside, 0);
+        assert_golden("messages", "status-over-dice", &frame);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn commitment_fits_a_short_screen() {
+        let short = Commitment::to_seed(2418).short();
+        let message = short_wording([
+            format!(
+                "Hash of the seed:\n{short}\n\nThe seed is revealed\nonce the game is over.\n\nBreak the beam to roll."
+            ),
+            format!("Hash: {short}\nRevealed after the game.\nBreak the beam to roll."),
+        ]);
+        let frame = short_screen(|frame| {
+            messages::small_message(&message, frame).unwrap();
+            Ok(())
+        });
+        assert_eq!(frame.outside, 0);
+        assert_golden("messages", "short-commitment", &frame);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn seed_fits_a_short_screen() {
+        let short = Commitment::to_seed(u64::MAX).short();
+        let seed = u64::MAX;
+        let message = short_wording([
+            format!(
+                "Seed of the game:\n{seed}\n\nHash before the game:\n{short}\n\nVerify it on a host."
+            ),
+            format!("Seed of the game:\n{seed}\nHash: {short}"),
+        ]);
+        let frame = short_screen(|frame| {
+            messages::small_message(&message, frame).unwrap();
+            Ok(())
+        });
+        assert_eq!(frame.outside, 0);
+        assert_golden("messages", "short-seed", &frame);
+    }
 
-        display.clear(BinaryColor::Off)?;
-        game.picked.draw(&mut display)?;
-        window.update(&display);
-        thread::sleep(SLEEP_FOR);
+    #[rstest]
+    #[test_log::test]
+    fn fairness_fits_a_short_screen() {
+        let message = short_wording([
+            format!(
+                "Self-test: dice {}\n{} rolls\nUniform:     {}\n chi-square {:.2}\nIndependent: {}\n correlation {:.4}",
+                "NOT fair", 6000, "pass", 4.2683, "FAIL", -0.0712
+            ),
+            format!(
+                "Self-test: dice {}\nUniform:     {} {:.1}\nIndependent: {} {:.3}",
+                "NOT fair", "pass", 4.2683, "FAIL", -0.0712
+            ),
+        ]);
+        let frame = short_screen(|frame| {
+            messages::small_message(&message, frame).unwrap();
+            Ok(())
+        });
+        assert_eq!(frame.outside, 0);
+        assert_golden("messages", "short-fairness", &frame);
+    }
 
-        Ok(())
+    #[rstest]
+    #[test_log::test]
+    fn scoreboard_is_paged_on_a_short_screen() {
+        let rows = messages::small_message_rows(Size::new(SCREEN_WIDTH, SHORT_SCREEN_HEIGHT));
+        let lines: Vec<String> = (1..=6)
+            .map(|player| {
+                let turn = if player == 2 { '>' } else { ' ' };
+                let name = format!("Player {player}");
+                format!("{turn}{name:<12}{:>4}{:>4}\n", player * 3, player % 2)
+            })
+            .collect();
+
+        let pages: Vec<String> = lines
+            .chunks(rows - 1)
+            .map(|players| format!("Round 2\n{}", players.concat()))
+            .collect();
+        assert_eq!(pages.len(), 3);
+        for (page, scoreboard) in pages.iter().enumerate() {
+            let frame = short_screen(|frame| {
+                messages::small_message(scoreboard, frame).unwrap();
+                Ok(())
+            });
+            assert_eq!(frame.outside, 0);
+            assert_golden("messages", &format!("short-scoreboard-{page}"), &frame);
+        }
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn theme_preview_fits_below_its_text(
+        #[values(SCREEN_HEIGHT, SHORT_SCREEN_HEIGHT)] height: u32,
+    ) {
+        let (name, theme) = DieStyle::THEMES[1];
+        let faces: Dice = [
+            FaceValue::One,
+            FaceValue::Two,
+            FaceValue::Three,
+            FaceValue::Four,
+            FaceValue::Five,
+            FaceValue::Six,
+        ]
+        .map(Die::new)
+        .to_vec()
+        .into();
+        let frame = Frame::render(SCREEN_WIDTH, height, |frame| {
+            let message = format!("Dice theme: {name}\nShort: next, long: keep");
+            let text = messages::small_message(&message, frame).unwrap().unwrap();
+            let preview_top = text.bottom_right().unwrap().y as u32 + 1 + 2;
+            let preview_area = Rectangle::new(
+                Point::new(0, preview_top as i32),
+                Size::new(SCREEN_WIDTH, height - preview_top),
+            );
+            let style = RenderStyle::PLAIN.themed(theme);
+            faces.draw_styled(&mut frame.cropped(&preview_area), |_| style)
+        });
+        assert_eq!(frame.outside, 0);
+        assert_golden("messages", &format!("theme-{height}"), &frame);
     }
 }
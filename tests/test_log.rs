@@ -0,0 +1,161 @@
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use game_logic::dice_game::DiceGame;
+    use game_logic::two_four_eighteen::log::{GameLog, LogError, RollEvent};
+    use game_logic::two_four_eighteen::pick::Selection;
+    use game_logic::two_four_eighteen::rules::Rules;
+    use game_logic::two_four_eighteen::{Game, NumberOfDice};
+    use pico_display::dice::Dice;
+    use pico_display::die::{Die, FaceValue};
+
+    fn play(game: &mut Game) {
+        while game.dice_left > NumberOfDice::Zero {
+            game.roll();
+        }
+    }
+
+    fn dice(values: &[FaceValue]) -> Dice {
+        Dice::from(
+            values
+                .iter()
+                .map(|&value| Die::new(value))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn every_roll_is_logged(#[values(0, 1, 2418)] seed: u64) {
+        let mut game = Game::from_seed(seed, Rules::TWO_FOUR_EIGHTEEN);
+        play(&mut game);
+
+        assert_eq!(game.log.seed, Some(seed));
+        let kept: usize = game.log.events().iter().map(|e| e.kept().len()).sum();
+        assert_eq!(kept, 5);
+        let last = game.log.events().last().unwrap();
+        assert_eq!(last.dice_left, 0);
+        assert_eq!(last.score, game.score());
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn replay_rebuilds_the_game(#[values(7, 42, 2418)] seed: u64) {
+        let mut game = Game::from_seed(seed, Rules::TWO_FOUR_EIGHTEEN);
+        play(&mut game);
+
+        let replayed = GameLog::from_bytes(&game.log.to_bytes())
+            .unwrap()
+            .replay(Rules::TWO_FOUR_EIGHTEEN)
+            .unwrap();
+        assert!(replayed.picked == game.picked);
+        assert!(replayed.rolled == game.rolled);
+        assert!(replayed.dice_left == game.dice_left);
+        assert_eq!(replayed.log, game.log);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn replayed_game_rolls_on_like_the_game(
+        #[values(7, 42, 2418)] seed: u64,
+        #[values(1, 2)] rolls: usize,
+    ) {
+        let mut game = Game::from_seed(seed, Rules::TWO_FOUR_EIGHTEEN);
+        for _ in 0..rolls {
+            game.roll();
+        }
+        let mut replayed = game.log.replay(Rules::TWO_FOUR_EIGHTEEN).unwrap();
+
+        game.roll();
+        replayed.roll();
+        assert!(replayed.rolled == game.rolled);
+        assert_eq!(replayed.log, game.log);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn faces_the_seed_does_not_roll_are_rejected() {
+        let mut game = Game::from_seed(2418, Rules::TWO_FOUR_EIGHTEEN);
+        play(&mut game);
+
+        let mut log = GameLog::new(Some(2419));
+        for event in game.log.events() {
+            log.push(*event).unwrap();
+        }
+        assert_eq!(
+            log.replay(Rules::TWO_FOUR_EIGHTEEN).err(),
+            Some(LogError::Inconsistent)
+        );
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn seed_plays_the_same_game_again() {
+        let mut game = Game::from_seed(2418, Rules::TWO_FOUR_EIGHTEEN);
+        play(&mut game);
+
+        let mut again = Game::from_seed(game.log.seed.unwrap(), Rules::TWO_FOUR_EIGHTEEN);
+        play(&mut again);
+        assert_eq!(again.log, game.log);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn manual_picks_are_logged() {
+        let mut game = Game::from_seed(2418, Rules::TWO_FOUR_EIGHTEEN);
+        game.throw().unwrap();
+        let rolled = game.rolled.clone();
+        let mut selection = Selection::new(5);
+        selection.toggle();
+        game.keep(&selection).unwrap();
+
+        let event = game.log.events()[0];
+        assert!(event.rolled() == rolled);
+        assert!(event.kept() == dice(&[rolled.dice[0].value]));
+        assert_eq!(event.dice_left, 4);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn reseeding_starts_a_new_log() {
+        let mut game = Game::from_seed(1, Rules::TWO_FOUR_EIGHTEEN);
+        play(&mut game);
+        game.reseed(2);
+        assert!(game.log.is_empty());
+        assert_eq!(game.log.seed, Some(2));
+
+        DiceGame::reset(&mut game);
+        assert_eq!(game.log.seed, None);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn inconsistent_logs_are_rejected() {
+        let mut log = GameLog::new(None);
+        let rolled = dice(&[FaceValue::One, FaceValue::Two, FaceValue::Three]);
+        let kept = dice(&[FaceValue::Six]);
+        log.push(RollEvent::new(&rolled, &kept, 2, -1).unwrap())
+            .unwrap();
+        assert_eq!(
+            log.replay(Rules::TWO_FOUR_EIGHTEEN).err(),
+            Some(LogError::Inconsistent)
+        );
+
+        let mut bytes = GameLog::new(None).to_bytes();
+        bytes[0] = 2;
+        assert_eq!(GameLog::from_bytes(&bytes), Err(LogError::Malformed));
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn roll_event_is_displayed() {
+        let rolled = dice(&[FaceValue::Two, FaceValue::Four, FaceValue::Six]);
+        let kept = dice(&[FaceValue::Six]);
+        let event = RollEvent::new(&rolled, &kept, 2, 6).unwrap();
+        assert_eq!(
+            event.to_string(),
+            "rolled: 246, kept: 6, dice left: 2, score: 6"
+        );
+    }
+}
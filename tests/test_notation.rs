@@ -0,0 +1,262 @@
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use game_logic::notation::parser::{ParseError, ParseErrorKind};
+    use game_logic::notation::{self, Expression, Keep, Term, MAX_DICE, PRESETS};
+    use pico_display::polyhedral::Sides;
+
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    const ROLLS: usize = 2000;
+
+    fn rng() -> SmallRng {
+        SmallRng::seed_from_u64(0)
+    }
+
+    fn values(roll: &notation::Roll) -> Vec<u8> {
+        roll.dice.dice.iter().map(|die| die.value).collect()
+    }
+
+    #[rstest]
+    #[case::dice_plus_modifier("3d6+2", "3d6+2")]
+    #[case::single_die("d20", "1d20")]
+    #[case::upper_case("3D6", "3d6")]
+    #[case::keep_highest("4d6kh3", "4d6kh3")]
+    #[case::keep_defaults_to_highest("4d6k3", "4d6kh3")]
+    #[case::keep_lowest("2d20kl1", "2d20kl1")]
+    #[case::keep_one("2d20kh", "2d20kh1")]
+    #[case::drop_lowest("4d6dl1", "4d6dl1")]
+    #[case::drop_highest("3d8dh", "3d8dh1")]
+    #[case::percentile("d%", "1d100")]
+    #[case::exploding("3d6!", "3d6!6")]
+    #[case::exploding_at_least("3d10!>9", "3d10!>9")]
+    #[case::reroll_ones("2d6r", "2d6r1")]
+    #[case::reroll_at_most("2d6r<2", "2d6r<2")]
+    #[case::reroll_once("2d6ro1", "2d6ro1")]
+    #[case::all_modifiers("4d6r1!kh3", "4d6r1!6kh3")]
+    #[case::whitespace(" 1d20 - 1d4 + 3 ", "1d20-1d4+3")]
+    #[case::leading_minus("-2+1d4", "-2+1d4")]
+    #[case::constant("7", "7")]
+    #[test_log::test]
+    fn parses(#[case] input: &str, #[case] canonical: &str) {
+        let expression: Expression = input.parse().unwrap();
+        assert_eq!(expression.to_string(), canonical);
+        assert_eq!(Expression::parse(canonical), Ok(expression));
+    }
+
+    #[test_log::test]
+    fn parses_terms() {
+        let expression = Expression::parse("4d6kh3-2").unwrap();
+        let terms: Vec<_> = expression.terms().copied().collect();
+
+        assert_eq!(terms.len(), 2);
+        match terms[0] {
+            Term::Dice { negative, term } => {
+                assert!(!negative);
+                assert_eq!(term.count, 4);
+                assert_eq!(term.sides, Sides::D6);
+                assert_eq!(term.keep, Some(Keep::Highest(3)));
+                assert_eq!(term.explode, None);
+                assert_eq!(term.reroll, None);
+            }
+            term => panic!("unexpected term {term:?}"),
+        }
+        assert_eq!(
+            terms[1],
+            Term::Constant {
+                negative: true,
+                constant: 2
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::empty("", ParseErrorKind::Empty, 0)]
+    #[case::blank("  ", ParseErrorKind::Empty, 2)]
+    #[case::missing_sides("3d", ParseErrorKind::ExpectedNumber, 2)]
+    #[case::unsupported_sides("3d7", ParseErrorKind::UnsupportedSides(7), 2)]
+    #[case::huge_sides("1d1000", ParseErrorKind::UnsupportedSides(1000), 2)]
+    #[case::dangling_operator("3d6+", ParseErrorKind::UnexpectedEnd, 4)]
+    #[case::unknown_modifier("3d6x", ParseErrorKind::UnexpectedCharacter('x'), 3)]
+    #[case::unknown_drop("2d6dx", ParseErrorKind::UnexpectedCharacter('x'), 4)]
+    #[case::non_ascii("3d6 é", ParseErrorKind::UnexpectedCharacter('é'), 4)]
+    #[case::operator_first("*3", ParseErrorKind::UnexpectedCharacter('*'), 0)]
+    #[case::no_dice("0d6", ParseErrorKind::NoDice, 0)]
+    #[case::too_many_dice("1d4+64d6", ParseErrorKind::TooManyDice, 4)]
+    #[case::keep_too_many("4d6kh5", ParseErrorKind::KeepOutOfRange, 5)]
+    #[case::keep_none("4d6kh0", ParseErrorKind::KeepOutOfRange, 5)]
+    #[case::drop_all("2d6dl2", ParseErrorKind::KeepOutOfRange, 5)]
+    #[case::explode_forever("1d6!<6", ParseErrorKind::EveryFaceMatches, 3)]
+    #[case::reroll_forever("2d6r<6", ParseErrorKind::EveryFaceMatches, 3)]
+    #[case::duplicate_keep("1d6kh1kl1", ParseErrorKind::DuplicateModifier, 6)]
+    #[case::number_too_large("99999", ParseErrorKind::NumberTooLarge, 0)]
+    #[case::too_many_terms("1+1+1+1+1+1+1+1+1", ParseErrorKind::TooManyTerms, 16)]
+    #[test_log::test]
+    fn reports_errors_with_position(
+        #[case] input: &str,
+        #[case] kind: ParseErrorKind,
+        #[case] position: usize,
+    ) {
+        assert_eq!(Expression::parse(input), Err(ParseError { kind, position }));
+    }
+
+    #[test_log::test]
+    fn error_names_the_position() {
+        let err = Expression::parse("3d6x").unwrap_err();
+        assert_eq!(err.to_string(), "unexpected 'x' at 3");
+    }
+
+    #[test_log::test]
+    fn reroll_once_may_match_every_face() {
+        assert!(Expression::parse("1d4ro<4").is_ok());
+    }
+
+    #[test_log::test]
+    fn presets_parse() {
+        for preset in PRESETS {
+            assert!(Expression::parse(preset).is_ok(), "{preset}");
+        }
+    }
+
+    #[test_log::test]
+    fn constants_add_up_without_dice() {
+        let roll = notation::roll("2+3-1", &mut rng()).unwrap();
+        assert_eq!(roll.total, 4);
+        assert!(roll.dice.is_empty());
+    }
+
+    #[test_log::test]
+    fn sums_dice_and_modifier() {
+        let expression = Expression::parse("3d6+2").unwrap();
+        let mut rng = rng();
+        for _ in 0..ROLLS {
+            let roll = expression.roll(&mut rng);
+            assert_eq!(roll.dice.len(), 3);
+            assert!(roll.dice.dice.iter().all(|die| die.sides == Sides::D6));
+            assert!(values(&roll).iter().all(|value| (1..=6).contains(value)));
+            assert_eq!(roll.total, roll.dice.total() as i32 + 2);
+        }
+    }
+
+    #[test_log::test]
+    fn subtracts_terms() {
+        let expression = Expression::parse("1d20-1d4").unwrap();
+        let mut rng = rng();
+        for _ in 0..ROLLS {
+            let roll = expression.roll(&mut rng);
+            let values = values(&roll);
+            assert_eq!(roll.total, values[0] as i32 - values[1] as i32);
+        }
+    }
+
+    #[rstest]
+    #[case::keep_highest("4d6kh3", 1)]
+    #[case::keep_lowest("2d20kl1", 1)]
+    #[case::drop_highest("5d8dh2", 2)]
+    #[case::drop_lowest("4d6dl1", 1)]
+    #[test_log::test]
+    fn keeps_and_drops(#[case] input: &str, #[case] dropped: usize) {
+        let expression = Expression::parse(input).unwrap();
+        let keeps_highest = input.contains("kh") || input.contains("dl");
+        let mut rng = rng();
+        for _ in 0..ROLLS {
+            let roll = expression.roll(&mut rng);
+            let kept: Vec<u8> = roll.kept().map(|die| die.value).collect();
+            let dropped_values: Vec<u8> = (0..roll.dice.len())
+                .filter(|&index| !roll.is_kept(index))
+                .map(|index| roll.dice.dice[index].value)
+                .collect();
+
+            assert_eq!(dropped_values.len(), dropped);
+            assert_eq!(roll.total, kept.iter().map(|&value| value as i32).sum());
+            for value in dropped_values {
+                if keeps_highest {
+                    assert!(kept.iter().all(|&kept| kept >= value));
+                } else {
+                    assert!(kept.iter().all(|&kept| kept <= value));
+                }
+            }
+        }
+    }
+
+    #[test_log::test]
+    fn ability_scores_average_above_three_dice() {
+        let expression = Expression::parse("4d6kh3").unwrap();
+        let mut rng = rng();
+        let total: i32 = (0..ROLLS).map(|_| expression.roll(&mut rng).total).sum();
+        let average = total as f64 / ROLLS as f64;
+        // 12.24 for 4d6 keep the highest three, 10.5 for 3d6
+        assert!((average - 12.24).abs() < 0.3, "average {average}");
+    }
+
+    #[test_log::test]
+    fn rerolls_until_no_match() {
+        let expression = Expression::parse("10d6r<2").unwrap();
+        let mut rng = rng();
+        for _ in 0..ROLLS {
+            let roll = expression.roll(&mut rng);
+            assert_eq!(roll.dice.len(), 10);
+            assert!(values(&roll).iter().all(|&value| value > 2));
+        }
+    }
+
+    #[test_log::test]
+    fn rerolls_once() {
+        let expression = Expression::parse("10d6ro<5").unwrap();
+        let mut rng = rng();
+        let ones: usize = (0..ROLLS)
+            .map(|_| {
+                let roll = expression.roll(&mut rng);
+                assert_eq!(roll.dice.len(), 10);
+                values(&roll).iter().filter(|&&value| value == 1).count()
+            })
+            .sum();
+        // a one needs a roll of five or less followed by a one
+        assert!(ones > 0);
+        assert!(ones < ROLLS * 10 / 6, "{ones} ones");
+    }
+
+    #[test_log::test]
+    fn explodes_on_the_highest_face() {
+        let expression = Expression::parse("5d6!").unwrap();
+        let mut rng = rng();
+        let mut exploded = false;
+        for _ in 0..ROLLS {
+            let roll = expression.roll(&mut rng);
+            let values = values(&roll);
+            let sixes = values.iter().filter(|&&value| value == 6).count();
+            assert_eq!(values.len(), 5 + sixes);
+            assert_eq!(roll.total, values.iter().map(|&value| value as i32).sum());
+            exploded |= sixes > 0;
+        }
+        assert!(exploded);
+    }
+
+    #[test_log::test]
+    fn explosions_stop_at_max_dice() {
+        let roll = notation::roll("64d4!>2", &mut rng()).unwrap();
+        assert_eq!(roll.dice.len(), MAX_DICE);
+    }
+
+    #[test_log::test]
+    fn displays_dropped_dice_in_parentheses() {
+        let roll = notation::roll("4d6kh3", &mut rng()).unwrap();
+        let shown = roll.to_string();
+        assert_eq!(shown.matches('(').count(), 1);
+        assert!(shown.ends_with(&format!(" = {}", roll.total)));
+    }
+
+    #[test_log::test]
+    fn dropped_dice_are_drawn_inverted() {
+        let roll = notation::roll("2d20kh1", &mut rng()).unwrap();
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        roll.dice
+            .draw_selection(&mut display, usize::MAX, |index| !roll.is_kept(index))
+            .unwrap();
+    }
+}
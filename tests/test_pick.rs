@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use game_logic::two_four_eighteen::pick::{PickError, Selection};
+    use game_logic::two_four_eighteen::rules::Rules;
+    use game_logic::two_four_eighteen::{Game, NumberOfDice};
+    use pico_display::dice::Dice;
+    use pico_display::die::{Die, FaceValue};
+
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[fixture]
+    fn game() -> Game {
+        Game::new(SmallRng::seed_from_u64(2418), Rules::TWO_FOUR_EIGHTEEN)
+    }
+
+    fn select(number_of_dice: usize, indices: &[usize]) -> Selection {
+        let mut selection = Selection::new(number_of_dice);
+        for index in 0..number_of_dice {
+            if indices.contains(&index) {
+                selection.toggle();
+            }
+            selection.next();
+        }
+        selection
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn cursor_wraps_around() {
+        let mut selection = Selection::new(3);
+        for _ in 0..4 {
+            selection.next();
+        }
+        assert_eq!(selection.cursor(), 1);
+
+        selection.toggle();
+        assert!(selection.is_selected(1));
+        selection.toggle();
+        assert!(!selection.has_selected());
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn keep_selected_dice(#[from(game)] mut game: Game) {
+        game.throw().unwrap();
+        assert!(game.is_awaiting_pick());
+
+        let rolled = game.rolled.clone();
+        game.keep(&select(5, &[1, 3])).unwrap();
+
+        assert!(game.dice_left == NumberOfDice::Three);
+        assert!(game.picked.dice[0] == rolled.dice[1]);
+        assert!(game.picked.dice[1] == rolled.dice[3]);
+        assert!(!game.is_awaiting_pick());
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn at_least_one_die_needs_to_be_picked(#[from(game)] mut game: Game) {
+        game.throw().unwrap();
+        assert_eq!(game.keep(&Selection::new(5)), Err(PickError::NothingPicked));
+        assert!(game.dice_left == NumberOfDice::Five);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn illegal_picks_are_rejected(#[from(game)] mut game: Game) {
+        assert_eq!(game.keep(&select(5, &[0])), Err(PickError::NotRolled));
+
+        game.throw().unwrap();
+        assert_eq!(game.throw(), Err(PickError::AlreadyRolled));
+        assert_eq!(
+            game.keep(&select(4, &[0])),
+            Err(PickError::InvalidSelection)
+        );
+
+        // keeping every die is only allowed when the qualifiers are among them
+        game.rolled = Dice::from(
+            [
+                FaceValue::Four,
+                FaceValue::Two,
+                FaceValue::Six,
+                FaceValue::Six,
+                FaceValue::Six,
+            ]
+            .map(Die::new)
+            .to_vec(),
+        );
+        game.keep(&select(5, &[0, 1, 2, 3, 4])).unwrap();
+        assert_eq!(game.throw(), Err(PickError::NoDiceLeft));
+        assert_eq!(game.keep(&select(5, &[0])), Err(PickError::NotRolled));
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn qualifiers_are_still_required(#[from(game)] mut game: Game) {
+        while game.dice_left > NumberOfDice::Zero {
+            game.throw().unwrap();
+            let number_of_dice = game.rolled.len();
+            game.keep(&select(number_of_dice, &[0])).unwrap();
+        }
+        let qualified = game.picked.dice.iter().any(|die| die.value.as_u8() == 4)
+            && game.picked.dice.iter().any(|die| die.value.as_u8() == 2);
+        assert_eq!(game.has_fish(), !qualified);
+        assert_eq!(game.score() == -1, !qualified);
+    }
+
+    /// Three sixes are picked, so a four and a two are missing from the two dice rolled.
+    fn game_missing_both_qualifiers(rolled: [FaceValue; 2]) -> Game {
+        let mut game = Game::new(SmallRng::seed_from_u64(2418), Rules::TWO_FOUR_EIGHTEEN);
+        game.picked = Dice::from(vec![Die::new(FaceValue::Six); 3]);
+        game.rolled = Dice::from(rolled.map(Die::new).to_vec());
+        game.dice_left = NumberOfDice::Two;
+        game
+    }
+
+    #[rstest]
+    #[case::cargo_instead_of_qualifier(&[1])]
+    #[case::cargo_with_qualifier(&[0, 1])]
+    #[test_log::test]
+    fn picks_that_give_up_on_the_qualifiers_are_rejected(#[case] indices: &[usize]) {
+        let mut game = game_missing_both_qualifiers([FaceValue::Four, FaceValue::Five]);
+        assert_eq!(
+            game.keep(&select(2, indices)),
+            Err(PickError::QualifiersOutOfReach)
+        );
+        assert!(game.is_awaiting_pick());
+
+        game.keep(&select(2, &[0])).unwrap();
+        assert!(game.dice_left == NumberOfDice::One);
+        assert!(!game.has_won());
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn any_pick_is_allowed_once_the_fish_cannot_be_avoided() {
+        let mut game = game_missing_both_qualifiers([FaceValue::Five, FaceValue::Six]);
+        game.keep(&select(2, &[1])).unwrap();
+        assert!(game.dice_left == NumberOfDice::One);
+    }
+}
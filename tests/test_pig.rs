@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use game_logic::dice_game::{DiceGame, Outcome};
+    use game_logic::pig::strategy::{HoldAtN, Strategy, DEFAULT_HOLD_AT};
+    use game_logic::pig::{Action, Game, Player, Turn, DEFAULT_TARGET_SCORE};
+    use pico_display::die::FaceValue;
+
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    const MAX_MOVES: usize = 10_000;
+
+    fn rolled(game: &Game) -> FaceValue {
+        game.rolled.dice[0].value
+    }
+
+    /// Plays the human's turns with `human` until the game is over.
+    fn play_out(game: &mut Game, mut human: HoldAtN) {
+        for _ in 0..MAX_MOVES {
+            if game.is_over() {
+                return;
+            }
+            let action = match game.player() {
+                Player::Human
+                    if human.bank(
+                        game.turn_total,
+                        game.score(Player::Human),
+                        game.score(Player::Computer),
+                        game.target_score,
+                    ) =>
+                {
+                    Action::Bank
+                }
+                _ => Action::Roll,
+            };
+            game.play(action);
+        }
+        panic!("game did not end within {MAX_MOVES} moves");
+    }
+
+    #[test_log::test]
+    fn rolls_add_up_until_a_one() {
+        let mut game = Game::new(SmallRng::seed_from_u64(0), DEFAULT_TARGET_SCORE);
+        let mut turn_total = 0;
+        for _ in 0..MAX_MOVES {
+            game.play(Action::Roll);
+            let event = *game.last_event().unwrap();
+            assert_eq!(event.player, Player::Human);
+            if rolled(&game) == FaceValue::One {
+                assert_eq!(event.turn, Turn::Busted);
+                assert_eq!(game.turn_total, 0);
+                assert_eq!(game.score(Player::Human), 0);
+                assert_eq!(game.player(), Player::Computer);
+                return;
+            }
+            turn_total += rolled(&game).as_u8() as u16;
+            assert_eq!(event.turn, Turn::Rolled);
+            assert_eq!(game.turn_total, turn_total);
+        }
+        panic!("never rolled a one");
+    }
+
+    #[test_log::test]
+    fn banking_scores_the_turn_total_and_passes_the_die() {
+        let mut game = Game::new(SmallRng::seed_from_u64(3), DEFAULT_TARGET_SCORE);
+        while game.turn_total == 0 || game.player() != Player::Human {
+            game.reset();
+            game.play(Action::Roll);
+        }
+        let turn_total = game.turn_total;
+
+        game.play(Action::Bank);
+        assert_eq!(game.score(Player::Human), turn_total);
+        assert_eq!(game.turn_total, 0);
+        assert_eq!(game.player(), Player::Computer);
+        assert_eq!(game.last_event().unwrap().turn, Turn::Banked);
+    }
+
+    #[test_log::test]
+    fn bank_before_the_first_roll_rolls() {
+        let mut game = Game::new(SmallRng::seed_from_u64(1), DEFAULT_TARGET_SCORE);
+        game.play(Action::Bank);
+
+        let event = game.last_event().unwrap();
+        assert_ne!(event.turn, Turn::Banked);
+        assert_eq!(game.rolled.len(), 1);
+        assert_eq!(game.score(Player::Human), 0);
+    }
+
+    #[rstest]
+    #[case::below_n(19, 0, 100, false)]
+    #[case::at_n(20, 0, 100, true)]
+    #[case::above_n(25, 0, 100, true)]
+    #[case::reaches_target(6, 94, 100, true)]
+    #[case::short_of_target(5, 94, 100, false)]
+    #[test_log::test]
+    fn hold_at_n_banks(
+        #[case] turn_total: u16,
+        #[case] score: u16,
+        #[case] target_score: u16,
+        #[case] banks: bool,
+    ) {
+        assert_eq!(
+            HoldAtN(DEFAULT_HOLD_AT).bank(turn_total, score, 0, target_score),
+            banks
+        );
+    }
+
+    #[test_log::test]
+    fn computer_plays_until_it_banks_or_busts() {
+        let mut game = Game::with_opponent(SmallRng::seed_from_u64(7), 100, HoldAtN(10));
+        // bust or bank right away to pass the die
+        game.play(Action::Roll);
+        if game.player() == Player::Human {
+            game.play(Action::Bank);
+        }
+        assert_eq!(game.player(), Player::Computer);
+
+        let mut moves = 0;
+        while game.player() == Player::Computer {
+            // the computer ignores the human's action
+            game.play(Action::Bank);
+            let event = game.last_event().unwrap();
+            assert_eq!(event.player, Player::Computer);
+            if event.turn == Turn::Banked {
+                assert!(event.turn_total >= 10);
+                assert_eq!(game.score(Player::Computer), event.turn_total);
+            }
+            moves += 1;
+            assert!(moves < MAX_MOVES);
+        }
+    }
+
+    #[rstest]
+    #[case::low_target(20)]
+    #[case::default_target(DEFAULT_TARGET_SCORE)]
+    #[case::high_target(250)]
+    #[test_log::test]
+    fn game_ends_at_the_target_score(#[case] target_score: u16) {
+        for seed in 0..20 {
+            let mut game = Game::new(SmallRng::seed_from_u64(seed), target_score);
+            play_out(&mut game, HoldAtN(25));
+
+            let human = game.score(Player::Human);
+            let computer = game.score(Player::Computer);
+            match game.outcome() {
+                Outcome::Won(score) => {
+                    assert_eq!(game.winner(), Some(Player::Human));
+                    assert!(human >= target_score);
+                    assert!(computer < target_score);
+                    assert_eq!(score as u16, human);
+                }
+                Outcome::Lost(score) => {
+                    assert_eq!(game.winner(), Some(Player::Computer));
+                    assert!(computer >= target_score);
+                    assert!(human < target_score);
+                    assert_eq!(score as u16, human);
+                }
+                outcome => panic!("unexpected outcome {outcome:?}"),
+            }
+
+            // nothing changes once the game is over
+            game.play(Action::Roll);
+            assert_eq!(game.score(Player::Human), human);
+            assert_eq!(game.score(Player::Computer), computer);
+        }
+    }
+
+    #[test_log::test]
+    fn rolling_through_is_a_dice_game() {
+        let mut game = Game::new(SmallRng::seed_from_u64(42), 50);
+        let mut rolls = 0;
+        while !DiceGame::is_over(&game) {
+            DiceGame::roll(&mut game);
+            rolls += 1;
+            assert!(rolls < MAX_MOVES);
+        }
+        // never banking, the human can only lose
+        assert!(matches!(game.outcome(), Outcome::Lost(0)));
+        assert_eq!(game.final_score(), Some(0));
+
+        DiceGame::reset(&mut game);
+        assert_eq!(game.outcome(), Outcome::Playing);
+        assert_eq!(game.player(), Player::Human);
+        assert!(game.last_event().is_none());
+    }
+}
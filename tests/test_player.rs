@@ -1,122 +1,54 @@
+mod headless;
+
 #[cfg(test)]
 mod tests {
-    use rand;
-    use rstest::{fixture, rstest};
+    use rstest::rstest;
 
     use game_logic::player;
 
     use core::convert::Infallible;
-    use embedded_graphics::{pixelcolor::BinaryColor, prelude::*};
-    use embedded_graphics_simulator::{OutputSettingsBuilder, SimulatorDisplay, Window};
 
     use rand::rngs::SmallRng;
     use rand::SeedableRng;
 
-    const SCALE: u32 = 5;
-    const SCREEN_WIDTH: u32 = SCALE * 128;
-    const SCREEN_HEIGHT: u32 = SCALE * 64;
-
-    use lazy_static::lazy_static;
-    use std::sync::Mutex;
-
-    lazy_static! {
-        static ref TEST_MUTEX: Mutex<()> = Mutex::new(());
-    }
-
-    type Display = SimulatorDisplay<BinaryColor>;
-
-    fn draw_in_window(display: &Display) -> Result<(), Infallible> {
-        let output_settings = OutputSettingsBuilder::new().scale(1).build();
-        Window::new("a die", &output_settings).show_static(&display);
-
-        Ok(())
-    }
-
-    #[fixture]
-    fn init_display() -> Display {
-        SimulatorDisplay::new(Size::new(SCREEN_WIDTH, SCREEN_HEIGHT))
-    }
-
-    #[fixture]
-    fn gen_small_rng() -> SmallRng {
-        let seed: u64 = rand::random();
-        SmallRng::seed_from_u64(seed)
-    }
-
-    #[rstest]
-    #[test_log::test]
-    #[ignore]
-    fn roll_die(
-        #[from(init_display)] mut display: Display,
-        #[from(gen_small_rng)] small_rng: SmallRng,
-    ) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-        player::roll_die(&mut display, small_rng)?;
+    use crate::headless::{assert_golden, Frame};
 
-        draw_in_window(&display)
-    }
+    const SCREEN_WIDTH: u32 = 128;
+    const SCREEN_HEIGHT: u32 = 64;
+    const SEED: u64 = 2418;
 
-    #[rstest]
-    #[test_log::test]
-    #[ignore]
-    fn roll_two_dice(
-        #[from(init_display)] mut display: Display,
-        #[from(gen_small_rng)] small_rng: SmallRng,
-    ) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-        player::roll_two_dice(&mut display, small_rng)?;
+    type Roll = fn(&mut Frame, SmallRng) -> Result<SmallRng, Infallible>;
 
-        draw_in_window(&display)
+    fn render(roll: Roll, seed: u64) -> Frame {
+        Frame::render(SCREEN_WIDTH, SCREEN_HEIGHT, |frame| {
+            roll(frame, SmallRng::seed_from_u64(seed)).map(|_| ())
+        })
     }
 
     #[rstest]
+    #[case::roll_die("roll-die", player::roll_die)]
+    #[case::roll_two_dice("roll-two-dice", player::roll_two_dice)]
+    #[case::roll_three_dice("roll-three-dice", player::roll_three_dice)]
+    #[case::roll_four_dice("roll-four-dice", player::roll_four_dice)]
+    #[case::roll_five_dice("roll-five-dice", player::roll_five_dice)]
     #[test_log::test]
-    #[ignore]
-    fn roll_three_dice(
-        #[from(init_display)] mut display: Display,
-        #[from(gen_small_rng)] small_rng: SmallRng,
-    ) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-        player::roll_three_dice(&mut display, small_rng)?;
-
-        draw_in_window(&display)
+    fn roll_dice(#[case] name: &str, #[case] roll: Roll) {
+        assert_golden("player", name, &render(roll, SEED));
     }
 
     #[rstest]
     #[test_log::test]
-    #[ignore]
-    fn roll_four_dice(
-        #[from(init_display)] mut display: Display,
-        #[from(gen_small_rng)] small_rng: SmallRng,
-    ) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-        player::roll_four_dice(&mut display, small_rng)?;
-
-        draw_in_window(&display)
+    fn roll_one_to_five_number_of_dice(#[values(0, 1, 2, 3, 4, 5, 6, 7)] seed: u64) {
+        let frame = render(player::roll_one_to_five_number_of_dice, seed);
+        assert_golden("player", &format!("roll-one-to-five-{seed}"), &frame);
     }
 
     #[rstest]
     #[test_log::test]
-    #[ignore]
-    fn roll_five_dice(
-        #[from(init_display)] mut display: Display,
-        #[from(gen_small_rng)] small_rng: SmallRng,
-    ) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-        player::roll_five_dice(&mut display, small_rng)?;
-
-        draw_in_window(&display)
-    }
-
-    #[rstest]
-    #[test_log::test]
-    fn roll_one_to_five_number_of_dice(
-        #[from(init_display)] mut display: Display,
-        #[from(gen_small_rng)] small_rng: SmallRng,
-    ) -> Result<(), Infallible> {
-        let _guard = TEST_MUTEX.lock().unwrap();
-        player::roll_one_to_five_number_of_dice(&mut display, small_rng)?;
-
-        draw_in_window(&display)
+    fn rolls_are_repeatable() {
+        let first = render(player::roll_five_dice, SEED);
+        let again = render(player::roll_five_dice, SEED);
+        assert!(first == again);
+        assert!(render(player::roll_five_dice, SEED + 1) != first);
     }
 }
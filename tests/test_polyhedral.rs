@@ -0,0 +1,158 @@
+mod mock;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use embedded_graphics::prelude::*;
+    use embedded_graphics::primitives::Rectangle;
+    use pico_display::dice::Dice;
+    use pico_display::die::DieFace;
+    use pico_display::polyhedral::{PolyhedralDie, Sides};
+
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::mock::{display, lit_pixels};
+
+    const SAMPLES: usize = 20_000;
+
+    #[rstest]
+    #[case::d4(Sides::D4, 4)]
+    #[case::d6(Sides::D6, 6)]
+    #[case::d8(Sides::D8, 8)]
+    #[case::d10(Sides::D10, 10)]
+    #[case::d12(Sides::D12, 12)]
+    #[case::d20(Sides::D20, 20)]
+    #[case::d100(Sides::D100, 100)]
+    #[test_log::test]
+    fn sides_count(#[case] sides: Sides, #[case] count: u8) {
+        assert_eq!(sides.count(), count);
+        assert_eq!(Sides::from_count(count), Some(sides));
+        assert_eq!(sides.to_string(), format!("d{count}"));
+    }
+
+    #[rstest]
+    #[case::zero(0)]
+    #[case::three(3)]
+    #[case::seven(7)]
+    #[case::two_hundred(200)]
+    #[test_log::test]
+    fn no_such_die(#[case] count: u8) {
+        assert_eq!(Sides::from_count(count), None);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn samples_every_face(
+        #[values(
+            Sides::D4,
+            Sides::D6,
+            Sides::D8,
+            Sides::D10,
+            Sides::D12,
+            Sides::D20,
+            Sides::D100
+        )]
+        sides: Sides,
+    ) {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut seen = vec![0usize; sides.count() as usize];
+        for _ in 0..SAMPLES {
+            let die: PolyhedralDie = rng.sample(sides);
+            assert_eq!(die.sides, sides);
+            assert!((1..=sides.count()).contains(&die.value));
+            seen[die.value as usize - 1] += 1;
+        }
+
+        let expected = SAMPLES / sides.count() as usize;
+        for (face, &count) in seen.iter().enumerate() {
+            assert!(
+                count > expected / 2 && count < expected * 3 / 2,
+                "face {} came up {count} times, expected about {expected}",
+                face + 1
+            );
+        }
+    }
+
+    #[test_log::test]
+    fn rolls_dice_of_a_kind() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let dice = Dice::roll_sides(Sides::D8, 4, &mut rng);
+
+        assert_eq!(dice.len(), 4);
+        assert!(dice.dice.iter().all(|die| die.sides == Sides::D8));
+        assert_eq!(
+            dice.total(),
+            dice.dice.iter().map(|die| die.value as u32).sum::<u32>()
+        );
+    }
+
+    #[test_log::test]
+    fn orders_by_value() {
+        let dice = Dice::from(vec![
+            PolyhedralDie::new(Sides::D20, 7),
+            PolyhedralDie::new(Sides::D20, 19),
+            PolyhedralDie::new(Sides::D20, 3),
+        ]);
+        assert_eq!(
+            dice.dice.iter().max(),
+            Some(&PolyhedralDie::new(Sides::D20, 19))
+        );
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn draws_within_the_target(
+        #[values(
+            Sides::D4,
+            Sides::D6,
+            Sides::D8,
+            Sides::D10,
+            Sides::D12,
+            Sides::D20,
+            Sides::D100
+        )]
+        sides: Sides,
+        #[values(64, 32, 21)] side_length: u32,
+    ) {
+        for value in [1, sides.count()] {
+            let die = PolyhedralDie::new(sides, value);
+            let mut display = display();
+            let area = Rectangle::new(Point::zero(), Size::new(side_length, side_length));
+            die.draw(&mut display.cropped(&area)).unwrap();
+            let outline_and_numeral = lit_pixels(&display);
+            assert!(outline_and_numeral > 0);
+
+            let mut inverted = self::display();
+            die.draw_inverted(&mut inverted.cropped(&area)).unwrap();
+            assert!(lit_pixels(&inverted) > outline_and_numeral);
+        }
+    }
+
+    #[test_log::test]
+    fn draws_the_numeral() {
+        let draw = |value| {
+            let mut display = display();
+            PolyhedralDie::new(Sides::D20, value)
+                .draw(&mut display)
+                .unwrap();
+            display
+        };
+        assert_ne!(draw(1), draw(20));
+        assert_ne!(draw(12), draw(13));
+    }
+
+    #[test_log::test]
+    fn draws_a_grid_of_mixed_dice() {
+        let dice = Dice::from(
+            Sides::ALL
+                .iter()
+                .map(|&sides| PolyhedralDie::new(sides, sides.count()))
+                .collect::<Vec<_>>(),
+        );
+        let mut display = display();
+        dice.draw(&mut display).unwrap();
+        assert!(lit_pixels(&display) > 0);
+    }
+}
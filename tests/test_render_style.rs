@@ -0,0 +1,129 @@
+mod mock;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use embedded_graphics::prelude::*;
+    use pico_display::dice::{Dice, RenderStyle};
+    use pico_display::polyhedral::Sides;
+
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::mock::{display, lit_points};
+
+    fn rolled(number_of_dice: u32, seed: u64) -> Dice {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        Dice::roll(|| rng.random(), number_of_dice)
+    }
+
+    fn styled(dice: &Dice, style: RenderStyle) -> MockDisplay<BinaryColor> {
+        let mut display = display();
+        dice.draw_styled(&mut display, |_| style).unwrap();
+        display
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(5)]
+    fn plain_dice_are_drawn_as_before(#[case] number_of_dice: u32) {
+        let dice = rolled(number_of_dice, 1);
+        let mut expected = display();
+        dice.draw(&mut expected).unwrap();
+        styled(&dice, RenderStyle::default()).assert_eq(&expected);
+        assert_eq!(RenderStyle::default(), RenderStyle::PLAIN);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(5)]
+    fn inverted_dice_are_drawn_like_selected_ones(#[case] number_of_dice: u32) {
+        let dice = rolled(number_of_dice, 2);
+        let mut expected = display();
+        dice.draw_selection(&mut expected, usize::MAX, |_| true)
+            .unwrap();
+        styled(&dice, RenderStyle::PLAIN.inverted()).assert_eq(&expected);
+    }
+
+    #[rstest]
+    #[case(RenderStyle::PLAIN)]
+    #[case(RenderStyle::PLAIN.inverted())]
+    fn dimmed_dice_leave_every_other_pixel_off(#[case] style: RenderStyle) {
+        let dice = rolled(3, 3);
+        let full = lit_points(&styled(&dice, style));
+        let dimmed = lit_points(&styled(&dice, style.dimmed()));
+
+        assert!(dimmed.iter().all(|point| full.contains(point)));
+        assert!(dimmed.iter().all(|point| (point.x + point.y) % 2 == 0));
+        let half = full.len() / 2;
+        assert!(dimmed.len().abs_diff(half) < full.len() / 10);
+    }
+
+    #[test_log::test]
+    fn underlined_die_has_a_line_under_it() {
+        let dice = rolled(1, 4);
+        let display = styled(&dice, RenderStyle::PLAIN.underlined());
+        let bottom = |x| display.get_pixel(Point::new(x, 63));
+        assert_eq!(bottom(0), None);
+        assert_eq!(bottom(63), None);
+        assert!((4..60).all(|x| bottom(x) == Some(BinaryColor::On)));
+        // the shrunk die leaves a gap above the line
+        assert!((0..64).all(|x| display.get_pixel(Point::new(x, 62)).is_none()));
+    }
+
+    #[test_log::test]
+    fn underlined_die_is_shrunk_to_make_room() {
+        let dice = rolled(1, 5);
+        let plain = styled(&dice, RenderStyle::PLAIN).affected_area();
+        let underlined = styled(&dice, RenderStyle::PLAIN.underlined()).affected_area();
+        assert!(underlined.size.width < plain.size.width);
+        assert_eq!(underlined.bottom_right(), Some(Point::new(60, 63)));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(5)]
+    #[case(6)]
+    #[case(12)]
+    fn every_style_stays_inside_the_display(#[case] number_of_dice: u32) {
+        let dice = rolled(number_of_dice, number_of_dice as u64);
+        styled(&dice, RenderStyle::PLAIN.inverted().underlined().dimmed());
+    }
+
+    #[test_log::test]
+    fn styles_are_picked_per_die() {
+        let dice = rolled(2, 6);
+        let mut styled = display();
+        dice.draw_styled(&mut styled, |index| {
+            if index == 0 {
+                RenderStyle::PLAIN.inverted()
+            } else {
+                RenderStyle::PLAIN
+            }
+        })
+        .unwrap();
+
+        let mut expected = display();
+        dice.draw_selection(&mut expected, usize::MAX, |index| index == 0)
+            .unwrap();
+        styled.assert_eq(&expected);
+    }
+
+    #[test_log::test]
+    fn polyhedral_dice_can_be_styled() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let dice = Dice::roll_sides(Sides::D20, 3, &mut rng);
+        let mut display = display();
+        dice.draw_styled(&mut display, |index| match index {
+            0 => RenderStyle::PLAIN.inverted(),
+            1 => RenderStyle::PLAIN.underlined(),
+            _ => RenderStyle::PLAIN.dimmed(),
+        })
+        .unwrap();
+        assert!(!lit_points(&display).is_empty());
+    }
+}
@@ -0,0 +1,147 @@
+mod fixtures;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use game_logic::two_four_eighteen::pick::Selection;
+    use game_logic::two_four_eighteen::rules::{Rules, RulesError};
+    use game_logic::two_four_eighteen::simulation;
+    use game_logic::two_four_eighteen::strategy::DefaultStrategy;
+    use game_logic::two_four_eighteen::{Game, NumberOfDice};
+
+    use tracing::info;
+
+    use pico_display::die::FaceValue;
+
+    use crate::fixtures::dice;
+
+    const NUMBER_OF_GAMES: u32 = 2000;
+
+    #[rstest]
+    #[case::two_four_eighteen(Rules::TWO_FOUR_EIGHTEEN, &[4, 2, 6, 6, 6], 18)]
+    #[case::two_four_eighteen_fish(Rules::TWO_FOUR_EIGHTEEN, &[4, 4, 6, 6, 6], -1)]
+    #[case::ship_captain_crew(Rules::SHIP_CAPTAIN_CREW, &[6, 5, 4, 3, 1], 4)]
+    #[case::ship_captain_crew_fish(Rules::SHIP_CAPTAIN_CREW, &[6, 6, 4, 6, 6], 0)]
+    #[case::ship_captain_crew_out_of_order(Rules::SHIP_CAPTAIN_CREW, &[5, 6, 4, 6, 6], 0)]
+    #[case::ship_captain_crew_cargo_between(Rules::SHIP_CAPTAIN_CREW, &[6, 3, 5, 4, 6], 9)]
+    #[case::seven_dice(Rules::SEVEN_DICE, &[2, 6, 6, 4, 6, 6, 6], 30)]
+    #[test_log::test]
+    fn score_follows_rules(#[case] rules: Rules, #[case] picked: &[u8], #[case] score: i8) {
+        assert_eq!(rules.score(&dice(picked)), score);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn qualifiers_are_missing_in_order() {
+        let rules = Rules::SHIP_CAPTAIN_CREW;
+        let missing: Vec<u8> = rules
+            .missing_qualifiers(&dice(&[6, 4]))
+            .map(|value| value.as_u8())
+            .collect();
+        assert_eq!(missing, [5, 4]);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn qualifiers_kept_in_one_roll_count_in_any_order() {
+        let mut game = Game::from_seed(7, Rules::SHIP_CAPTAIN_CREW);
+        game.throw().unwrap();
+        game.rolled = dice(&[4, 1, 5, 6, 2]);
+        let mut selection = Selection::new(5);
+        for index in 0..5 {
+            if index != 1 && index != 4 {
+                selection.toggle();
+            }
+            selection.next();
+        }
+        game.keep(&selection).unwrap();
+
+        assert!(game.picked == dice(&[6, 5, 4]));
+        assert_eq!(game.rules.missing_qualifiers(&game.picked).count(), 0);
+        assert!(game.log.events()[0].kept() == dice(&[6, 5, 4]));
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn ordered_qualifiers_are_picked_up_to_the_first_missing() {
+        let rules = Rules::SHIP_CAPTAIN_CREW;
+        let mut rolled = dice(&[5, 4, 3]);
+        assert!(rules.pick_qualifiers(&dice(&[]), &mut rolled).is_empty());
+        assert!(rules.pick_qualifiers(&dice(&[6]), &mut rolled) == dice(&[5, 4]));
+        assert!(!rules.can_win(&dice(&[5, 6])));
+        assert!(rules.can_win(&dice(&[6, 6, 5])));
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn can_win_only_with_sixes_besides_qualifiers() {
+        let rules = Rules::TWO_FOUR_EIGHTEEN;
+        assert!(rules.can_win(&dice(&[4, 6, 2])));
+        assert!(!rules.can_win(&dice(&[4, 4])));
+        assert!(!rules.can_win(&dice(&[5])));
+        assert_eq!(rules.max_score(), rules.winning_score);
+    }
+
+    #[rstest]
+    #[case::too_many_qualifiers(&[FaceValue::Six; 6], NumberOfDice::Five, 0, Err(RulesError::TooManyQualifiers))]
+    #[case::out_of_reach(&[FaceValue::Four, FaceValue::Two], NumberOfDice::Five, 19, Err(RulesError::WinningScoreOutOfReach))]
+    #[case::two_four_eighteen(&[FaceValue::Four, FaceValue::Two], NumberOfDice::Five, 18, Ok(Rules::TWO_FOUR_EIGHTEEN))]
+    #[test_log::test]
+    fn rules_are_validated(
+        #[case] qualifiers: &'static [FaceValue],
+        #[case] number_of_dice: NumberOfDice,
+        #[case] winning_score: i8,
+        #[case] expected: Result<Rules, RulesError>,
+    ) {
+        let rules = Rules::new(qualifiers, number_of_dice, winning_score, -1);
+        assert!(rules == expected);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn rules_with_too_many_qualifiers_cannot_score() {
+        let rules = Rules {
+            qualifiers: &[FaceValue::Six; 6],
+            ..Rules::TWO_FOUR_EIGHTEEN
+        };
+        assert_eq!(rules.max_score(), 0);
+    }
+
+    #[rstest]
+    #[case::ship_captain_crew(Rules::SHIP_CAPTAIN_CREW)]
+    #[case::seven_dice(Rules::SEVEN_DICE)]
+    #[test_log::test]
+    fn variants_are_played_to_the_end(#[case] rules: Rules) {
+        let statistics = simulation::simulate_with(NUMBER_OF_GAMES, 0, rules, |_| DefaultStrategy);
+        info!("{statistics}");
+
+        let games: u32 = statistics.score_histogram().map(|(_, games)| games).sum();
+        assert_eq!(games, NUMBER_OF_GAMES);
+        assert_eq!(
+            statistics.games_with_score(rules.fish_penalty),
+            statistics.fish
+        );
+        assert_eq!(
+            statistics.games_with_score(rules.winning_score),
+            statistics.wins
+        );
+        assert!(statistics.wins > 0);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn seven_dice_are_rolled() {
+        let mut game = Game::from_seed(7, Rules::SEVEN_DICE);
+        assert!(game.dice_left == NumberOfDice::Seven);
+        game.throw().unwrap();
+        assert_eq!(game.rolled.len(), 7);
+
+        while game.dice_left > NumberOfDice::Zero {
+            game.roll();
+        }
+        assert_eq!(game.picked.len(), 7);
+        let replayed = game.log.replay(Rules::SEVEN_DICE).unwrap();
+        assert!(replayed.picked == game.picked);
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use game_logic::session::{RoundState, Session, SessionError};
+
+    #[fixture]
+    fn session() -> Session {
+        Session::new(&["Ada", "Bo", "Cy"]).unwrap()
+    }
+
+    fn scores(session: &Session) -> Vec<Option<i16>> {
+        session
+            .players()
+            .iter()
+            .map(|player| player.score)
+            .collect()
+    }
+
+    #[rstest]
+    #[case(&[], SessionError::TooFewPlayers)]
+    #[case(&["Ada"], SessionError::TooFewPlayers)]
+    #[case(&["A", "B", "C", "D", "E", "F", "G"], SessionError::TooManyPlayers)]
+    #[test_log::test]
+    fn number_of_players_is_checked(#[case] names: &[&'static str], #[case] error: SessionError) {
+        assert_eq!(Session::new(names), Err(error));
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn turns_rotate(#[from(session)] mut session: Session) {
+        assert_eq!(session.current_player(), Some(0));
+        assert_eq!(session.record(12), RoundState::Playing);
+        assert_eq!(session.current_player(), Some(1));
+        assert_eq!(session.record(-1), RoundState::Playing);
+        assert_eq!(session.current_player(), Some(2));
+        assert_eq!(scores(&session), vec![Some(12), Some(-1), None]);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn highest_score_wins(#[from(session)] mut session: Session) {
+        session.record(12);
+        session.record(18);
+        assert_eq!(session.record(3), RoundState::Won(1));
+
+        let winner = session.winner().unwrap();
+        assert_eq!(winner.name, "Bo");
+        assert_eq!(winner.rounds_won, 1);
+        assert_eq!(session.current_player(), None);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn ties_are_rolled_off(#[from(session)] mut session: Session) {
+        session.record(15);
+        session.record(9);
+        assert_eq!(session.record(15), RoundState::RollOff);
+        assert_eq!(scores(&session), vec![None, Some(9), None]);
+        assert_eq!(session.current_player(), Some(0));
+
+        assert_eq!(session.record(10), RoundState::RollOff);
+        assert_eq!(session.current_player(), Some(2));
+        assert_eq!(session.record(10), RoundState::RollOff);
+
+        session.record(-1);
+        assert_eq!(session.record(4), RoundState::Won(2));
+        assert_eq!(session.round(), 1);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn next_round_starts_with_next_player(#[from(session)] mut session: Session) {
+        session.record(4);
+        session.record(5);
+        assert_eq!(session.record(6), RoundState::Won(2));
+
+        assert_eq!(session.record(7), RoundState::Playing);
+        assert_eq!(session.round(), 2);
+        assert_eq!(scores(&session), vec![None, Some(7), None]);
+        assert_eq!(session.current_player(), Some(2));
+
+        session.record(8);
+        assert_eq!(session.record(9), RoundState::Won(0));
+        assert_eq!(session.players()[0].rounds_won, 1);
+        assert_eq!(session.players()[2].rounds_won, 1);
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use game_logic::two_four_eighteen::rules::Rules;
+    use game_logic::two_four_eighteen::simulation;
+    use game_logic::two_four_eighteen::strategy::GreedyStrategy;
+
+    use tracing::info;
+
+    const NUMBER_OF_GAMES: u32 = 10000;
+    const SEED: u64 = 2418;
+    const MIN_WIN_RATE: f64 = 0.065;
+    const MAX_FISH_RATE: f64 = 0.15;
+
+    #[rstest]
+    #[test_log::test]
+    fn default_strategy_does_not_regress() {
+        let statistics = simulation::simulate(NUMBER_OF_GAMES, SEED);
+        info!("{statistics}");
+
+        assert!(statistics.win_rate() >= MIN_WIN_RATE);
+        assert!(statistics.fish_rate() <= MAX_FISH_RATE);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn statistics_add_up() {
+        let statistics =
+            simulation::simulate_with(NUMBER_OF_GAMES, SEED, Rules::TWO_FOUR_EIGHTEEN, |_| {
+                GreedyStrategy
+            });
+        for (score, games) in statistics.score_histogram().filter(|(_, games)| *games > 0) {
+            info!("{score:>3}: {games}");
+        }
+
+        let games: u32 = statistics.score_histogram().map(|(_, games)| games).sum();
+        assert_eq!(games, NUMBER_OF_GAMES);
+        assert_eq!(statistics.games_with_score(-1), statistics.fish);
+        assert_eq!(statistics.games_with_score(18), statistics.wins);
+        assert!((1.0..=5.0).contains(&statistics.average_rolls()));
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn simulation_is_reproducible() {
+        assert_eq!(
+            simulation::simulate(NUMBER_OF_GAMES, SEED),
+            simulation::simulate(NUMBER_OF_GAMES, SEED)
+        );
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use game_logic::two_four_eighteen::optimal::{self, OptimalStrategy};
+    use game_logic::two_four_eighteen::rules::Rules;
+    use game_logic::two_four_eighteen::simulation;
+    use game_logic::two_four_eighteen::solver::{Objective, Position, ValueTable};
+    use game_logic::two_four_eighteen::NumberOfDice;
+    use pico_display::dice::Dice;
+    use pico_display::die::{Die, FaceValue};
+
+    use tracing::info;
+
+    const NUMBER_OF_GAMES: u32 = 5000;
+
+    fn qualified(dice_left: u8) -> Position {
+        Position {
+            has_four: true,
+            has_two: true,
+            dice_left,
+            total: 6,
+        }
+    }
+
+    #[rstest]
+    #[case(Objective::ExpectedScore)]
+    #[case(Objective::WinProbability)]
+    #[test_log::test]
+    fn precomputed_table_matches_solver(#[case] objective: Objective) {
+        let solved = ValueTable::solve(objective);
+        assert_eq!(optimal::value_table(objective).values(), solved.values());
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn expected_score_of_last_die() {
+        let table = optimal::value_table(Objective::ExpectedScore);
+        assert_eq!(table.value(qualified(1)), 3.5);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn expected_score_of_last_two_dice() {
+        let mut expected = 0.0;
+        for first in 1..=6 {
+            for second in 1..=6 {
+                let keep_both = (first + second) as f64;
+                let reroll_one = first.max(second) as f64 + 3.5;
+                expected += keep_both.max(reroll_one) / 36.0;
+            }
+        }
+
+        let table = optimal::value_table(Objective::ExpectedScore);
+        assert!((table.value(qualified(2)) as f64 - expected).abs() < 1e-5);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn fish_is_certain_without_qualifiers() {
+        let picked = Dice::from(vec![Die::new(FaceValue::Six); 4]);
+        assert_eq!(optimal::expected_score(&picked, NumberOfDice::One), -1.0);
+        assert_eq!(optimal::win_probability(&picked, NumberOfDice::One), 0.0);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn optimal_strategy_beats_default_strategy() {
+        let picked = Dice::empty();
+        info!(
+            "expected score: {}, win probability: {}",
+            optimal::expected_score(&picked, NumberOfDice::Five),
+            optimal::win_probability(&picked, NumberOfDice::Five)
+        );
+
+        let default = simulation::simulate(NUMBER_OF_GAMES, 0);
+        let optimal_score =
+            simulation::simulate_with(NUMBER_OF_GAMES, 0, Rules::TWO_FOUR_EIGHTEEN, |_| {
+                OptimalStrategy::new(Objective::ExpectedScore)
+            });
+        let optimal_win =
+            simulation::simulate_with(NUMBER_OF_GAMES, 0, Rules::TWO_FOUR_EIGHTEEN, |_| {
+                OptimalStrategy::new(Objective::WinProbability)
+            });
+        info!("default: {default}");
+        info!("optimal score: {optimal_score}");
+        info!("optimal win: {optimal_win}");
+
+        assert!(optimal_score.average_score() > default.average_score());
+        assert!(optimal_win.win_rate() > default.win_rate());
+    }
+}
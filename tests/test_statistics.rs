@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use game_logic::entropy::{EntropyPool, Source};
+    use game_logic::statistics::{
+        self_test, FaceStatistics, CHI_SQUARE_CRITICAL_VALUE, DEFAULT_ROLLS, MIN_ROLLS,
+    };
+    use pico_display::die::FaceValue;
+
+    use rand::rngs::SmallRng;
+    use rand::{Rng, SeedableRng};
+
+    fn face(value: u8) -> FaceValue {
+        match value {
+            1 => FaceValue::One,
+            2 => FaceValue::Two,
+            3 => FaceValue::Three,
+            4 => FaceValue::Four,
+            5 => FaceValue::Five,
+            _ => FaceValue::Six,
+        }
+    }
+
+    fn statistics_of(values: impl IntoIterator<Item = u8>) -> FaceStatistics {
+        let mut statistics = FaceStatistics::new();
+        for value in values {
+            statistics.record(face(value));
+        }
+        statistics
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(42)]
+    #[case(1_200_000)]
+    #[case(u64::MAX)]
+    fn small_rng_rolls_fair_faces(#[case] seed: u64) {
+        let fairness = self_test(&mut SmallRng::seed_from_u64(seed), DEFAULT_ROLLS);
+        assert_eq!(fairness.rolls, DEFAULT_ROLLS);
+        assert!(fairness.is_uniform(), "{fairness}");
+        assert!(fairness.is_independent(), "{fairness}");
+    }
+
+    #[rstest]
+    #[case(1_000_000)]
+    #[case(3_141_592)]
+    fn seeds_from_the_entropy_pool_roll_fair_faces(#[case] beam_timing: u64) {
+        let mut pool = EntropyPool::new();
+        pool.mix(Source::BeamTiming, beam_timing);
+        let fairness = self_test(&mut SmallRng::from_rng(&mut pool), DEFAULT_ROLLS);
+        assert!(fairness.passes(), "{fairness}");
+    }
+
+    #[test_log::test]
+    fn counts_every_face() {
+        let statistics = statistics_of([1, 2, 2, 6, 6, 6]);
+        assert_eq!(statistics.counts(), &[1, 2, 0, 0, 0, 3]);
+        assert_eq!(statistics.rolls(), 6);
+    }
+
+    #[test_log::test]
+    fn chi_square_of_known_counts() {
+        let values = [1; 20]
+            .into_iter()
+            .chain((2..=6).flat_map(|value| [value; 8]));
+        let statistics = statistics_of(values);
+        // (20 - 10)² / 10 + 5 × (8 - 10)² / 10
+        assert!((statistics.chi_square() - 12.0).abs() < 1e-9);
+    }
+
+    #[test_log::test]
+    fn loaded_die_is_not_uniform() {
+        let values =
+            (0..DEFAULT_ROLLS).map(|roll| if roll % 5 == 0 { 6 } else { roll as u8 % 6 + 1 });
+        let fairness = statistics_of(values).report();
+        assert!(fairness.chi_square > CHI_SQUARE_CRITICAL_VALUE);
+        assert!(!fairness.is_uniform());
+        assert!(!fairness.passes());
+    }
+
+    #[test_log::test]
+    fn cycling_die_is_uniform_but_not_independent() {
+        let fairness = statistics_of((0..DEFAULT_ROLLS).map(|roll| (roll % 6) as u8 + 1)).report();
+        assert!(fairness.is_uniform(), "{fairness}");
+        assert!(!fairness.is_independent(), "{fairness}");
+        assert!(!fairness.passes());
+    }
+
+    #[test_log::test]
+    fn sticky_die_is_not_independent() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut statistics = FaceStatistics::new();
+        for _ in 0..DEFAULT_ROLLS / 2 {
+            let value: FaceValue = rng.random();
+            statistics.record(value);
+            statistics.record(value);
+        }
+        let fairness = statistics.report();
+        assert!(fairness.is_uniform(), "{fairness}");
+        assert!(fairness.serial_correlation > 0.4, "{fairness}");
+        assert!(!fairness.is_independent());
+    }
+
+    #[test_log::test]
+    fn constant_die_fails_everything() {
+        let fairness = statistics_of([4; MIN_ROLLS as usize]).report();
+        assert_eq!(fairness.serial_correlation, 1.0);
+        assert!(!fairness.is_uniform());
+        assert!(!fairness.is_independent());
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(MIN_ROLLS - 1)]
+    fn too_few_rolls_prove_nothing(#[case] rolls: u32) {
+        let fairness = self_test(&mut SmallRng::seed_from_u64(0), rolls);
+        assert!(!fairness.passes());
+    }
+
+    #[rstest]
+    #[case(DEFAULT_ROLLS, "pass")]
+    #[case(MIN_ROLLS - 1, "fail")]
+    fn report_reads_pass_or_fail(#[case] rolls: u32, #[case] verdict: &str) {
+        let report = self_test(&mut SmallRng::seed_from_u64(0), rolls).to_string();
+        assert!(
+            report.starts_with(&format!("{rolls} rolls, chi-square: ")),
+            "{report}"
+        );
+        assert!(report.contains(&format!("({verdict})")), "{report}");
+    }
+}
@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use game_logic::two_four_eighteen::rules::Rules;
+    use game_logic::two_four_eighteen::strategy::{
+        AlwaysMaxStrategy, DefaultStrategy, GreedyStrategy, RandomStrategy, Strategy,
+    };
+    use game_logic::two_four_eighteen::{Game, NumberOfDice};
+    use pico_display::dice::Dice;
+    use pico_display::die::{Die, FaceValue};
+
+    use tracing::info;
+
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    const NUMBER_OF_GAMES: u64 = 1000;
+
+    struct KeepAbsentSix;
+
+    impl Strategy for KeepAbsentSix {
+        fn pick(
+            &mut self,
+            _rules: &Rules,
+            _rolled: &Dice,
+            _picked: &Dice,
+            _dice_left: NumberOfDice,
+        ) -> Dice {
+            dice(&[FaceValue::Six, FaceValue::Six, FaceValue::Six])
+        }
+    }
+
+    fn dice(values: &[FaceValue]) -> Dice {
+        Dice::from(
+            values
+                .iter()
+                .map(|&value| Die::new(value))
+                .collect::<Vec<Die>>(),
+        )
+    }
+
+    fn values(dice: &Dice) -> Vec<u8> {
+        let mut values: Vec<u8> = dice.dice.iter().map(|die| die.value.as_u8()).collect();
+        values.sort();
+        values
+    }
+
+    fn play<S>(game: &mut Game<S>) -> u32
+    where
+        S: Strategy,
+    {
+        let mut rolls = 0;
+        while game.dice_left > NumberOfDice::Zero {
+            game.roll();
+            rolls += 1;
+        }
+        rolls
+    }
+
+    fn average_score<S, F>(new_strategy: F) -> f64
+    where
+        S: Strategy,
+        F: Fn(u64) -> S,
+    {
+        let total: i64 = (0..NUMBER_OF_GAMES)
+            .map(|seed| {
+                let mut game = Game::with_strategy(
+                    SmallRng::seed_from_u64(seed),
+                    Rules::TWO_FOUR_EIGHTEEN,
+                    new_strategy(seed),
+                );
+                let rolls = play(&mut game);
+                assert!(rolls <= 5);
+                assert_eq!(game.picked.len(), 5);
+                game.score() as i64
+            })
+            .sum();
+        total as f64 / NUMBER_OF_GAMES as f64
+    }
+
+    #[fixture]
+    fn rolled_without_fish() -> Dice {
+        dice(&[
+            FaceValue::Six,
+            FaceValue::Two,
+            FaceValue::One,
+            FaceValue::Four,
+            FaceValue::Five,
+        ])
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn default_strategy_goes_for_the_win(#[from(rolled_without_fish)] rolled: Dice) {
+        let kept = DefaultStrategy.pick(
+            &Rules::TWO_FOUR_EIGHTEEN,
+            &rolled,
+            &Dice::empty(),
+            NumberOfDice::Five,
+        );
+        assert_eq!(values(&kept), vec![2, 4, 6]);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn greedy_strategy_keeps_above_average(#[from(rolled_without_fish)] rolled: Dice) {
+        let kept = GreedyStrategy.pick(
+            &Rules::TWO_FOUR_EIGHTEEN,
+            &rolled,
+            &Dice::empty(),
+            NumberOfDice::Five,
+        );
+        assert_eq!(values(&kept), vec![2, 4, 5, 6]);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn always_max_strategy_keeps_one_die(#[from(rolled_without_fish)] rolled: Dice) {
+        let kept = AlwaysMaxStrategy.pick(
+            &Rules::TWO_FOUR_EIGHTEEN,
+            &rolled,
+            &Dice::empty(),
+            NumberOfDice::Five,
+        );
+        assert_eq!(values(&kept), vec![4]);
+
+        let picked = dice(&[FaceValue::Four, FaceValue::Two]);
+        let kept = AlwaysMaxStrategy.pick(
+            &Rules::TWO_FOUR_EIGHTEEN,
+            &rolled,
+            &picked,
+            NumberOfDice::Three,
+        );
+        assert_eq!(values(&kept), vec![6]);
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn game_ignores_dice_that_were_not_rolled() {
+        let mut game = Game::with_strategy(
+            SmallRng::seed_from_u64(18),
+            Rules::TWO_FOUR_EIGHTEEN,
+            KeepAbsentSix,
+        );
+        while game.dice_left > NumberOfDice::Zero {
+            let dice_left = game.dice_left;
+            let picked = game.picked.len();
+            game.roll();
+
+            let sixes = game
+                .rolled
+                .dice
+                .iter()
+                .filter(|die| die.value == FaceValue::Six);
+            let expected = sixes.count().clamp(1, 3);
+            assert_eq!(game.picked.len() - picked, expected);
+            assert!(game.dice_left < dice_left);
+        }
+    }
+
+    #[rstest]
+    #[test_log::test]
+    fn compare_strategies() {
+        let default = average_score(|_| DefaultStrategy);
+        let greedy = average_score(|_| GreedyStrategy);
+        let always_max = average_score(|_| AlwaysMaxStrategy);
+        let random = average_score(|seed| RandomStrategy::new(SmallRng::seed_from_u64(!seed)));
+
+        info!("default: {default:.2}, greedy: {greedy:.2}, always max: {always_max:.2}, random: {random:.2}");
+
+        assert!(default > random);
+    }
+}
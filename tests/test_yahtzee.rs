@@ -0,0 +1,319 @@
+mod fixtures;
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use embedded_graphics::prelude::*;
+    use game_logic::dice_game::{DiceGame, Outcome};
+    use game_logic::yahtzee::scorecard::{
+        Category, Scorecard, ScorecardError, NUMBER_OF_ENTRIES, UPPER_BONUS, YAHTZEE_BONUS,
+    };
+    use game_logic::yahtzee::strategy::{DefaultStrategy, Strategy};
+    use game_logic::yahtzee::{Game, NUMBER_OF_TURNS, ROLLS_PER_TURN};
+    use pico_display::dice::Dice;
+    use pico_display::scorecard::{self, Entry};
+
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    use crate::fixtures::dice;
+
+    const SCREEN_WIDTH: u32 = 128;
+    const SCREEN_HEIGHT: u32 = 64;
+
+    /// A headless frame to draw into, since the simulator window needs SDL.
+    struct Frame {
+        size: Size,
+        pixels: Vec<bool>,
+    }
+
+    impl Frame {
+        fn new(width: u32, height: u32) -> Self {
+            Self {
+                size: Size::new(width, height),
+                pixels: vec![false; (width * height) as usize],
+            }
+        }
+
+        fn lit_columns(&self) -> impl Iterator<Item = u32> + '_ {
+            (0..self.size.width).filter(|&x| {
+                (0..self.size.height).any(|y| self.pixels[(y * self.size.width + x) as usize])
+            })
+        }
+    }
+
+    impl OriginDimensions for Frame {
+        fn size(&self) -> Size {
+            self.size
+        }
+    }
+
+    impl DrawTarget for Frame {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            for Pixel(point, color) in pixels {
+                if self.bounding_box().contains(point) {
+                    let index = (point.y as u32 * self.size.width + point.x as u32) as usize;
+                    self.pixels[index] = color.is_on();
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn fill(scorecard: &mut Scorecard, category: Category, values: &[u8]) -> u8 {
+        scorecard.fill(category, &dice(values)).unwrap()
+    }
+
+    #[rstest]
+    #[case::ones(Category::Ones, &[1, 1, 3, 1, 6], 3)]
+    #[case::twos(Category::Twos, &[2, 2, 3, 1, 6], 4)]
+    #[case::threes(Category::Threes, &[3, 3, 3, 3, 6], 12)]
+    #[case::fours(Category::Fours, &[1, 1, 3, 1, 6], 0)]
+    #[case::fives(Category::Fives, &[5, 1, 5, 5, 5], 20)]
+    #[case::sixes(Category::Sixes, &[6, 6, 6, 6, 6], 30)]
+    #[case::three_of_a_kind(Category::ThreeOfAKind, &[4, 4, 2, 4, 6], 20)]
+    #[case::no_three_of_a_kind(Category::ThreeOfAKind, &[4, 4, 2, 2, 6], 0)]
+    #[case::four_of_a_kind(Category::FourOfAKind, &[4, 4, 4, 4, 6], 22)]
+    #[case::no_four_of_a_kind(Category::FourOfAKind, &[4, 4, 2, 4, 6], 0)]
+    #[case::full_house(Category::FullHouse, &[3, 5, 3, 5, 3], 25)]
+    #[case::no_full_house(Category::FullHouse, &[3, 5, 3, 5, 1], 0)]
+    #[case::small_straight(Category::SmallStraight, &[3, 1, 4, 2, 4], 30)]
+    #[case::small_straight_high(Category::SmallStraight, &[6, 3, 4, 5, 1], 30)]
+    #[case::no_small_straight(Category::SmallStraight, &[1, 2, 3, 5, 6], 0)]
+    #[case::large_straight(Category::LargeStraight, &[2, 6, 4, 3, 5], 40)]
+    #[case::no_large_straight(Category::LargeStraight, &[1, 2, 3, 4, 6], 0)]
+    #[case::yahtzee(Category::Yahtzee, &[2, 2, 2, 2, 2], 50)]
+    #[case::no_yahtzee(Category::Yahtzee, &[2, 2, 2, 2, 1], 0)]
+    #[case::chance(Category::Chance, &[1, 2, 3, 4, 6], 16)]
+    #[test_log::test]
+    fn category_scores(#[case] category: Category, #[case] values: &[u8], #[case] score: u8) {
+        assert_eq!(category.score(&dice(values)), score);
+    }
+
+    #[test_log::test]
+    fn upper_bonus_needs_sixty_three() {
+        let mut scorecard = Scorecard::default();
+        fill(&mut scorecard, Category::Ones, &[1, 1, 1, 2, 3]);
+        fill(&mut scorecard, Category::Twos, &[2, 2, 2, 1, 3]);
+        fill(&mut scorecard, Category::Threes, &[3, 3, 3, 1, 2]);
+        fill(&mut scorecard, Category::Fours, &[4, 4, 4, 1, 2]);
+        fill(&mut scorecard, Category::Fives, &[5, 5, 5, 1, 2]);
+        fill(&mut scorecard, Category::Sixes, &[6, 6, 1, 2, 3]);
+        assert_eq!(scorecard.upper_total(), 57);
+        assert_eq!(scorecard.upper_bonus(), 0);
+
+        let mut scorecard = Scorecard::default();
+        fill(&mut scorecard, Category::Ones, &[1, 1, 1, 2, 3]);
+        fill(&mut scorecard, Category::Twos, &[2, 2, 2, 1, 3]);
+        fill(&mut scorecard, Category::Threes, &[3, 3, 3, 1, 2]);
+        fill(&mut scorecard, Category::Fours, &[4, 4, 4, 1, 2]);
+        fill(&mut scorecard, Category::Fives, &[5, 5, 5, 1, 2]);
+        fill(&mut scorecard, Category::Sixes, &[6, 6, 6, 2, 3]);
+        assert_eq!(scorecard.upper_total(), 63);
+        assert_eq!(scorecard.upper_bonus(), UPPER_BONUS);
+        assert_eq!(scorecard.total(), 63 + UPPER_BONUS);
+    }
+
+    #[test_log::test]
+    fn filled_category_is_taken() {
+        let mut scorecard = Scorecard::default();
+        fill(&mut scorecard, Category::Chance, &[1, 2, 3, 4, 6]);
+        assert_eq!(
+            scorecard.fill(Category::Chance, &dice(&[6, 6, 6, 6, 5])),
+            Err(ScorecardError::Taken)
+        );
+        assert_eq!(scorecard.score(Category::Chance), Some(16));
+    }
+
+    #[test_log::test]
+    fn second_yahtzee_earns_bonus_and_is_joker() {
+        let mut scorecard = Scorecard::default();
+        fill(&mut scorecard, Category::Yahtzee, &[4, 4, 4, 4, 4]);
+
+        let fours = dice(&[4, 4, 4, 4, 4]);
+        assert!(scorecard.is_joker(&fours));
+        assert!(!scorecard.allows(Category::FullHouse, &fours));
+        assert_eq!(
+            scorecard.fill(Category::Chance, &fours),
+            Err(ScorecardError::JokerRule)
+        );
+        assert_eq!(scorecard.fill(Category::Fours, &fours), Ok(20));
+        assert_eq!(scorecard.yahtzee_bonus(), YAHTZEE_BONUS);
+
+        // with the upper category taken the joker scores anywhere in the lower section
+        assert!(scorecard.allows(Category::FullHouse, &fours));
+        assert!(!scorecard.allows(Category::Ones, &fours));
+        assert_eq!(scorecard.fill(Category::LargeStraight, &fours), Ok(40));
+        assert_eq!(scorecard.yahtzee_bonus(), 2 * YAHTZEE_BONUS);
+        assert_eq!(scorecard.total(), 50 + 20 + 40 + 2 * YAHTZEE_BONUS);
+    }
+
+    #[test_log::test]
+    fn scratched_yahtzee_earns_no_bonus() {
+        let mut scorecard = Scorecard::default();
+        fill(&mut scorecard, Category::Yahtzee, &[1, 2, 3, 4, 6]);
+        assert_eq!(
+            scorecard.fill(Category::Twos, &dice(&[2, 2, 2, 2, 2])),
+            Ok(10)
+        );
+        assert_eq!(scorecard.yahtzee_bonus(), 0);
+    }
+
+    #[test_log::test]
+    fn entries_hold_categories_bonuses_and_total() {
+        let mut scorecard = Scorecard::default();
+        fill(&mut scorecard, Category::Sixes, &[6, 6, 6, 6, 1]);
+        let entries = scorecard.entries();
+
+        assert_eq!(entries.len(), NUMBER_OF_ENTRIES);
+        assert_eq!(entries[5], Entry::new("Sixes", Some(24)));
+        assert_eq!(entries[6], Entry::new("Bonus", None));
+        assert_eq!(entries[7], Entry::new("3 kind", None));
+        assert_eq!(entries[13], Entry::new("Chance", None));
+        assert_eq!(entries[14], Entry::new("Yz bonus", Some(0)));
+        assert_eq!(entries[15], Entry::new("Total", Some(24)));
+    }
+
+    #[rstest]
+    #[case::full_screen(SCREEN_WIDTH, SCREEN_HEIGHT, 16, 1)]
+    #[case::next_to_dice(SCREEN_WIDTH / 2, SCREEN_HEIGHT, 8, 2)]
+    #[case::half_height(SCREEN_WIDTH, SCREEN_HEIGHT / 2, 8, 2)]
+    #[case::narrow(40, 16, 2, 8)]
+    #[test_log::test]
+    fn scorecard_pages(
+        #[case] width: u32,
+        #[case] height: u32,
+        #[case] per_page: usize,
+        #[case] pages: usize,
+    ) {
+        let size = Size::new(width, height);
+        assert_eq!(scorecard::entries_per_page(size), per_page);
+        assert_eq!(scorecard::number_of_pages(NUMBER_OF_ENTRIES, size), pages);
+    }
+
+    #[test_log::test]
+    fn scorecard_fits_the_display() {
+        let entries = Scorecard::default().entries();
+        let mut frame = Frame::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+        scorecard::draw_scorecard(&entries, 0, &mut frame).unwrap();
+
+        let lit: Vec<u32> = frame.lit_columns().collect();
+        assert!(lit.iter().any(|&x| x < SCREEN_WIDTH / 2));
+        assert!(lit.iter().any(|&x| x >= SCREEN_WIDTH / 2));
+    }
+
+    #[test_log::test]
+    fn scorecard_pages_next_to_the_dice() {
+        let entries = Scorecard::default().entries();
+        for page in 0..2 {
+            let mut frame = Frame::new(SCREEN_WIDTH / 2, SCREEN_HEIGHT);
+            scorecard::draw_scorecard(&entries, page, &mut frame).unwrap();
+            assert!(frame.lit_columns().next().is_some());
+        }
+
+        let mut frame = Frame::new(SCREEN_WIDTH / 2, SCREEN_HEIGHT);
+        scorecard::draw_scorecard(&entries, 2, &mut frame).unwrap();
+        assert!(frame.lit_columns().next().is_none());
+    }
+
+    #[rstest]
+    #[case::large_straight(&[1, 2, 3, 4, 5], [true; 5])]
+    #[case::full_house(&[2, 5, 2, 5, 5], [true; 5])]
+    #[case::yahtzee(&[3, 3, 3, 3, 3], [true; 5])]
+    #[case::most_frequent(&[6, 2, 6, 1, 4], [true, false, true, false, false])]
+    #[case::higher_of_two_pairs(&[2, 5, 2, 5, 1], [false, true, false, true, false])]
+    #[case::run_of_three(&[2, 3, 6, 4, 4], [true, true, false, true, false])]
+    #[test_log::test]
+    fn default_strategy_holds(#[case] rolled: &[u8], #[case] holds: [bool; 5]) {
+        let holds_of = DefaultStrategy.hold(&dice(rolled), 2, &Scorecard::default());
+        assert_eq!(holds_of, holds);
+    }
+
+    #[rstest]
+    #[case::yahtzee(&[5, 5, 5, 5, 5], Category::Yahtzee)]
+    #[case::large_straight(&[2, 3, 4, 5, 6], Category::LargeStraight)]
+    #[case::sixes(&[6, 6, 6, 6, 1], Category::Sixes)]
+    #[case::zero_in_ones(&[2, 3, 3, 4, 6], Category::Ones)]
+    #[test_log::test]
+    fn default_strategy_chooses(#[case] rolled: &[u8], #[case] category: Category) {
+        let chosen = DefaultStrategy.choose(&dice(rolled), &Scorecard::default());
+        assert_eq!(chosen, category);
+    }
+
+    #[test_log::test]
+    fn game_fills_the_scorecard() {
+        let mut game = Game::new(SmallRng::seed_from_u64(0));
+        let mut rolls = 0;
+        while !game.is_over() {
+            game.roll();
+            rolls += 1;
+            assert!(rolls <= NUMBER_OF_TURNS * ROLLS_PER_TURN as usize);
+        }
+
+        assert!(rolls >= NUMBER_OF_TURNS);
+        assert!(game.scorecard.is_complete());
+        assert_eq!(game.outcome(), Outcome::Over(game.total() as i16));
+        assert!(game.last_event().unwrap().scored.is_some());
+
+        game.reset();
+        assert_eq!(game.outcome(), Outcome::Playing);
+        assert_eq!(game.scorecard.total(), 0);
+    }
+
+    #[test_log::test]
+    fn turn_ends_after_three_rolls() {
+        struct Reroll;
+        impl Strategy for Reroll {
+            fn hold(&mut self, _: &Dice, _: u8, _: &Scorecard) -> [bool; 5] {
+                [false; 5]
+            }
+            fn choose(&mut self, _: &Dice, _: &Scorecard) -> Category {
+                Category::Chance
+            }
+        }
+
+        let mut game = Game::with_strategy(SmallRng::seed_from_u64(1), Reroll);
+        for _ in 0..ROLLS_PER_TURN - 1 {
+            game.roll();
+            assert!(game.last_event().unwrap().scored.is_none());
+        }
+        game.roll();
+        let event = game.last_event().unwrap();
+        assert_eq!(
+            event.scored.map(|(category, _)| category),
+            Some(Category::Chance)
+        );
+
+        // chance is taken, the next turn is scored in the best allowed category instead
+        for _ in 0..ROLLS_PER_TURN {
+            game.roll();
+        }
+        let (category, _) = game.last_event().unwrap().scored.unwrap();
+        assert_ne!(category, Category::Chance);
+    }
+
+    #[test_log::test]
+    fn default_strategy_scores_well() {
+        const GAMES: u64 = 200;
+        let total: u32 = (0..GAMES)
+            .map(|seed| {
+                let mut game = Game::new(SmallRng::seed_from_u64(seed));
+                while !game.is_over() {
+                    game.roll();
+                }
+                game.total() as u32
+            })
+            .sum();
+        let average = total / GAMES as u32;
+        tracing::info!("average score: {average}");
+        assert!(average >= 200, "average score {average}");
+    }
+}
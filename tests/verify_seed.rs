@@ -0,0 +1,58 @@
+//! Checks a seed revealed by the hub against the hash it showed before the game, and replays
+//! the game from the seed:
+//!
+//! cargo run --target x86_64-unknown-linux-gnu -p tests --example verify-seed -- SEED HASH [farkle]
+
+use std::env;
+use std::process::ExitCode;
+
+use game_logic::commitment::{self, Commitment};
+use game_logic::dice_game::DiceGame;
+use game_logic::farkle;
+use game_logic::two_four_eighteen::rules::Rules;
+use game_logic::two_four_eighteen::Game;
+
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+
+fn replay<G>(game: &mut G, seed: u64, shown: &str) -> ExitCode
+where
+    G: DiceGame,
+{
+    match commitment::verify(game, seed, shown) {
+        Ok(score) => {
+            println!("hash:  {}", Commitment::to_seed(seed));
+            println!("game:  {}", game.log());
+            println!("score: {score}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (Some(seed), Some(shown)) = (args.first(), args.get(1)) else {
+        eprintln!("usage: verify-seed SEED HASH [farkle]");
+        return ExitCode::FAILURE;
+    };
+    let Ok(seed) = seed.parse::<u64>() else {
+        eprintln!("not a seed: {seed}");
+        return ExitCode::FAILURE;
+    };
+    match args.get(2).map(String::as_str) {
+        Some("farkle") => replay(
+            &mut farkle::Game::new(SmallRng::seed_from_u64(seed)),
+            seed,
+            shown,
+        ),
+        _ => replay(
+            &mut Game::from_seed(seed, Rules::TWO_FOUR_EIGHTEEN),
+            seed,
+            shown,
+        ),
+    }
+}